@@ -8,7 +8,39 @@ pub trait Cell {
     /// Returns the [`Player`] who owns the cell. If the cell is empty or a drawn/in-progress
     /// game, return [`None`].
     fn owner(&self) -> Option<&Player>;
+    /// Returns whether nothing else can be played in the cell. Defaults to whether it has an
+    /// [`owner`](Cell::owner), but cells that can end without one, like drawn boards, must
+    /// override it so [`Board::get_state`](super::Board::get_state) can tell when the board is full.
+    fn is_decided(&self) -> bool {
+        self.owner().is_some()
+    }
     /// Returns the [`Cell`]'s representation as a [`char`]. Required to be able to have more
     /// nuanced representations of cells by [`super::BoardDisplay`].
     fn as_char(&self) -> char;
 }
+
+/// A [`Cell`] that can be given an owner, so generic algorithms like editors and fill utilities
+/// can change cells of any [`Board`](super::Board) they can get mutable access to.
+///
+/// # Examples
+/// ```
+/// use tic_tac_toe::{Player, board::{Board, InnerBoard, RecursiveBoard, cell::{Cell, CellMut}}};
+///
+/// fn fill<'a, C: CellMut + 'a>(cells: impl Iterator<Item = &'a mut C>, player: Player) {
+///     for cell in cells {
+///         cell.set_owner(Some(player));
+///     }
+/// }
+///
+/// let mut inner = InnerBoard::new();
+/// fill(inner.cells_mut(), Player::Cross);
+/// assert_eq!(inner.get_cell(8), &Some(Player::Cross));
+///
+/// let mut board = RecursiveBoard::new();
+/// fill(board.cells_mut(), Player::Circle);
+/// assert_eq!(board.get_cell(8).owner(), Some(&Player::Circle));
+/// ```
+pub trait CellMut: Cell {
+    /// Makes `owner` the [`owner`](Cell::owner) of the cell, or empties it for [`None`].
+    fn set_owner(&mut self, owner: Option<Player>);
+}
@@ -1,5 +1,6 @@
-use super::{Board, BoardDisplay, Player};
-use std::{fmt::Display, str::FromStr};
+use super::{Board, BoardDisplay, Player, WIN_LINES, position::InnerIdx};
+use crate::{BoardResult, BoardState, errors::InnerBoardFromStrError};
+use std::{fmt::Display, str::FromStr, sync::OnceLock};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 /// The inner-most board in the game. All of its cells are either empty or belong to a player.
@@ -23,6 +24,53 @@ impl InnerBoard {
         }
     }
 
+    #[must_use]
+    /// Returns a board with the given cells. Same as the [`From`] implementation, but usable in
+    /// `const` contexts.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::InnerBoard};
+    ///
+    /// const X: Option<Player> = Some(Player::Cross);
+    /// static BOARD: InnerBoard = InnerBoard::from_cells([X, None, None, None, X, None, None, None, X]);
+    ///
+    /// assert_eq!(BOARD, InnerBoard::from([X, None, None, None, X, None, None, None, X]));
+    /// ```
+    pub const fn from_cells(cells: [Option<Player>; 9]) -> Self {
+        Self { cells }
+    }
+
+    #[must_use]
+    /// Returns an [`InnerBoardBuilder`] for an empty board, to place marks cell by cell.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::str::FromStr;
+    /// use tic_tac_toe::board::InnerBoard;
+    ///
+    /// let board = InnerBoard::builder().x(0).o(4).x(8).build();
+    /// assert_eq!(board, InnerBoard::from_str("X---O---X").unwrap());
+    /// ```
+    pub const fn builder() -> InnerBoardBuilder {
+        InnerBoardBuilder { board: Self::new() }
+    }
+
+    #[must_use]
+    /// Returns the value of `cell`. Same as [`Board::get_cell`], but with a typed index that can't
+    /// be out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{InnerBoard, position::InnerIdx}};
+    ///
+    /// let board = InnerBoard::builder().o(4).build();
+    /// assert_eq!(board.cell(InnerIdx::CENTER), &Some(Player::Circle));
+    /// ```
+    pub const fn cell(&self, cell: InnerIdx) -> &Option<Player> {
+        &self.cells[cell.get()]
+    }
+
     /// Sets the given `cell` to the provided cell value.
     ///
     /// # Examples
@@ -39,14 +87,303 @@ impl InnerBoard {
     /// assert_eq!(board.get_cell(1), &None);
     /// ```
     pub fn set_cell(&mut self, cell: usize, value: Option<Player>) {
-        debug_assert!(cell < 9);
+        check_index!("InnerBoard", cell);
         self.cells[cell] = value;
     }
+
+    /// Sets every cell of the board to `owner`.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{InnerBoard, Board}};
+    ///
+    /// let mut board = InnerBoard::new();
+    /// board.fill(Some(Player::Circle));
+    /// assert!((0..9).all(|cell| board.get_cell(cell) == &Some(Player::Circle)));
+    /// ```
+    pub fn fill(&mut self, owner: Option<Player>) {
+        self.cells = [owner; 9];
+    }
+
+    /// Iterates mutably over the cells of the board, in index order.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{InnerBoard, Board}};
+    ///
+    /// let mut board = InnerBoard::new();
+    /// for cell in board.cells_mut().step_by(2) {
+    ///     *cell = Some(Player::Cross);
+    /// }
+    /// assert_eq!(board.get_cell(0), &Some(Player::Cross));
+    /// assert_eq!(board.get_cell(1), &None);
+    /// ```
+    pub fn cells_mut(&mut self) -> std::slice::IterMut<'_, Option<Player>> {
+        self.cells.iter_mut()
+    }
+
+    #[must_use]
+    /// Solves the board, assuming `next_player` is the one to move and both players play perfectly
+    /// from here on. Boards that are already over just return their result.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::str::FromStr;
+    /// use tic_tac_toe::{BoardResult, Player, board::InnerBoard};
+    ///
+    /// // An empty board is a draw with best play.
+    /// assert_eq!(InnerBoard::new().solve(Player::Cross), BoardResult::Draw);
+    ///
+    /// // Whoever moves next completes their own line first.
+    /// let board = InnerBoard::from_str("XX-OO----").unwrap();
+    /// assert_eq!(board.solve(Player::Cross), BoardResult::Winner(Player::Cross));
+    /// assert_eq!(board.solve(Player::Circle), BoardResult::Winner(Player::Circle));
+    /// ```
+    pub fn solve(&self, next_player: Player) -> BoardResult {
+        static TABLE: OnceLock<Vec<[i8; 2]>> = OnceLock::new();
+
+        // Every board is solved the first time any is, reusing the results of the positions
+        // that follow each one. There are only 3^9 boards, so the table stays small.
+        let table = TABLE.get_or_init(|| {
+            let mut table = vec![[UNSOLVED; 2]; BOARD_COUNT];
+            for index in 0..BOARD_COUNT {
+                let mut board = Self::from_base3_index(index);
+                for player in [Player::Cross, Player::Circle] {
+                    board.negamax(&mut table, index, player);
+                }
+            }
+            table
+        });
+
+        match table[self.base3_index()][side_index(next_player)] {
+            1 => BoardResult::Winner(next_player),
+            -1 => BoardResult::Winner(next_player.opponent()),
+            _ => BoardResult::Draw,
+        }
+    }
+
+    #[must_use]
+    /// Returns the [`BoardState`] of each of `boards`, in order. The results are the same as calling
+    /// [`Board::get_state`] on each of them.
+    ///
+    /// Each board is turned into a bitmask per player, and every line is checked with a single
+    /// mask comparison instead of looking up its three cells, for when many boards need to be
+    /// evaluated at once.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::str::FromStr;
+    /// use tic_tac_toe::{BoardResult, BoardState, Player, board::InnerBoard};
+    ///
+    /// let boards = [
+    ///     InnerBoard::new(),
+    ///     InnerBoard::from_str("OX-XXXO--").unwrap(),
+    ///     InnerBoard::from_str("OOXXXOOXO").unwrap(),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     InnerBoard::get_states_batch(&boards),
+    ///     vec![
+    ///         BoardState::InProgress,
+    ///         BoardState::Over(BoardResult::Winner(Player::Cross)),
+    ///         BoardState::Over(BoardResult::Draw),
+    ///     ]
+    /// );
+    /// ```
+    pub fn get_states_batch(boards: &[InnerBoard]) -> Vec<BoardState> {
+        // Taken from `WIN_LINES` in the order `Board::get_state` checks them (each row before
+        // the column with the same index, then the diagonals), so both agree on impossible boards
+        // where both players have a line.
+        const LINES: [u16; 8] = {
+            const ORDER: [usize; 8] = [0, 3, 1, 4, 2, 5, 6, 7];
+            let mut masks = [0; 8];
+            let mut index = 0;
+            while index < 8 {
+                let [a, b, c] = WIN_LINES[ORDER[index]];
+                masks[index] = (1 << a) | (1 << b) | (1 << c);
+                index += 1;
+            }
+            masks
+        };
+
+        boards
+            .iter()
+            .map(|board| {
+                let (mut circles, mut crosses) = (0u16, 0u16);
+                for (cell, value) in board.cells.iter().enumerate() {
+                    match value {
+                        Some(Player::Circle) => circles |= 1 << cell,
+                        Some(Player::Cross) => crosses |= 1 << cell,
+                        None => {}
+                    }
+                }
+
+                for line in LINES {
+                    if circles & line == line {
+                        return BoardState::Over(BoardResult::Winner(Player::Circle));
+                    }
+                    if crosses & line == line {
+                        return BoardState::Over(BoardResult::Winner(Player::Cross));
+                    }
+                }
+                if circles | crosses == 0b111_111_111 {
+                    BoardState::Over(BoardResult::Draw)
+                } else {
+                    BoardState::InProgress
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the empty cells in which `player` would complete a line, in ascending order.
+    pub(crate) fn winning_cells(&self, player: Player) -> impl Iterator<Item = usize> {
+        super::mask_cells(super::threat_mask(
+            |cell| self.cells[cell] == Some(player),
+            |cell| self.cells[cell].is_none(),
+        ))
+    }
+
+    /// Returns the cells in base 3, `0` for empty, `1` for [`Player::Cross`] and `2` for
+    /// [`Player::Circle`], with the first cell as the least significant digit. This is also the
+    /// payload of [`CompressedKey`](super::recursive::CompressedKey).
+    pub(crate) const fn base3_index(&self) -> usize {
+        let mut index = 0;
+        let mut cell = 9;
+        while cell > 0 {
+            cell -= 1;
+            index = index * 3 + base3_digit(self.cells[cell]);
+        }
+        index
+    }
+
+    /// The inverse of [`InnerBoard::base3_index`].
+    fn from_base3_index(mut index: usize) -> Self {
+        let mut board = Self::new();
+        for cell in &mut board.cells {
+            *cell = [None, Some(Player::Cross), Some(Player::Circle)][index % 3];
+            index /= 3;
+        }
+        board
+    }
+
+    /// Whether `player` still has a line with none of the opponent's marks, so the board could end
+    /// up won by them.
+    pub(crate) fn can_be_won_by(&self, player: Player) -> bool {
+        let opponent = Some(player.opponent());
+        WIN_LINES
+            .iter()
+            .any(|line| line.iter().all(|&cell| self.cells[cell] != opponent))
+    }
+
+    /// Scores the board from the point of view of `player`, who is the one to move:
+    /// `1` for a forced win, `0` for a draw and `-1` for a forced loss. `index` is the board's
+    /// [`base3_index`](InnerBoard::base3_index), and the scores are kept in `table`.
+    fn negamax(&mut self, table: &mut [[i8; 2]], index: usize, player: Player) -> i8 {
+        let solved = table[index][side_index(player)];
+        if solved != UNSOLVED {
+            return solved;
+        }
+
+        let best = match self.get_state() {
+            BoardState::Over(BoardResult::Winner(winner)) => {
+                if winner == player {
+                    1
+                } else {
+                    -1
+                }
+            }
+            BoardState::Over(BoardResult::Draw) => 0,
+            BoardState::InProgress => {
+                let mut best = -1;
+                let mut power = 1;
+                for cell in 0..9 {
+                    if self.cells[cell].is_none() {
+                        self.cells[cell] = Some(player);
+                        let child = index + power * base3_digit(Some(player));
+                        let score = -self.negamax(table, child, player.opponent());
+                        self.cells[cell] = None;
+
+                        if score > best {
+                            best = score;
+                            if best == 1 {
+                                break;
+                            }
+                        }
+                    }
+                    power *= 3;
+                }
+                best
+            }
+        };
+        table[index][side_index(player)] = best;
+        best
+    }
+}
+
+/// How many different [`InnerBoard`]s there are, counting unreachable ones.
+const BOARD_COUNT: usize = 3usize.pow(9);
+
+/// Marks the entries of the [`InnerBoard::solve`] table that haven't been filled in yet.
+const UNSOLVED: i8 = i8::MIN;
+
+/// The digit of a cell in [`InnerBoard::base3_index`].
+const fn base3_digit(cell: Option<Player>) -> usize {
+    match cell {
+        None => 0,
+        Some(Player::Cross) => 1,
+        Some(Player::Circle) => 2,
+    }
+}
+
+/// Which of the two columns of the [`InnerBoard::solve`] table is for `player` to move.
+const fn side_index(player: Player) -> usize {
+    match player {
+        Player::Cross => 0,
+        Player::Circle => 1,
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+/// Builds an [`InnerBoard`] one mark at a time. Returned by [`InnerBoard::builder`].
+///
+/// Later marks overwrite earlier ones in the same cell.
+pub struct InnerBoardBuilder {
+    board: InnerBoard,
+}
+
+impl InnerBoardBuilder {
+    #[must_use]
+    /// Places a [`Player::Cross`] mark in `cell`.
+    pub const fn x(self, cell: usize) -> Self {
+        self.mark(cell, Player::Cross)
+    }
+
+    #[must_use]
+    /// Places a [`Player::Circle`] mark in `cell`.
+    pub const fn o(self, cell: usize) -> Self {
+        self.mark(cell, Player::Circle)
+    }
+
+    #[must_use]
+    /// Places `player`'s mark in `cell`.
+    ///
+    /// # Panics
+    /// This will panic if `cell` is not inside the board.
+    pub const fn mark(mut self, cell: usize, player: Player) -> Self {
+        self.board.cells[cell] = Some(player);
+        self
+    }
+
+    #[must_use]
+    /// Returns the board built so far.
+    pub const fn build(self) -> InnerBoard {
+        self.board
+    }
 }
 
 impl Board<Option<Player>> for InnerBoard {
     fn get_cell(&self, cell: usize) -> &Option<Player> {
-        debug_assert!(cell < 9);
+        check_index!("InnerBoard", cell);
         &self.cells[cell]
     }
 }
@@ -66,6 +403,18 @@ impl super::cell::Cell for Option<Player> {
     }
 }
 
+impl super::FromCells<Option<Player>> for InnerBoard {
+    fn from_cells(cells: [Option<Player>; 9]) -> Self {
+        InnerBoard::from_cells(cells)
+    }
+}
+
+impl super::cell::CellMut for Option<Player> {
+    fn set_owner(&mut self, owner: Option<Player>) {
+        *self = owner;
+    }
+}
+
 impl Default for InnerBoard {
     fn default() -> Self {
         Self::new()
@@ -74,7 +423,31 @@ impl Default for InnerBoard {
 
 impl From<[Option<Player>; 9]> for InnerBoard {
     fn from(value: [Option<Player>; 9]) -> Self {
-        Self { cells: value }
+        Self::from_cells(value)
+    }
+}
+
+/// Generates positions of a standalone 3x3 game: [`Player::Cross`] starts, the players take turns
+/// on empty cells, and the game stops once the board is over or the data runs out.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InnerBoard {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut board = Self::new();
+        let mut player = Player::Cross;
+
+        for _ in 0..u.int_in_range(0..=9)? {
+            if board.get_state() != BoardState::InProgress {
+                break;
+            }
+            let empty: Vec<usize> = (0..9).filter(|&cell| board.cells[cell].is_none()).collect();
+            board.cells[empty[u.choose_index(empty.len())?]] = Some(player);
+            player = player.opponent();
+        }
+        Ok(board)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, None)
     }
 }
 
@@ -85,26 +458,45 @@ impl Display for InnerBoard {
 }
 
 impl FromStr for InnerBoard {
-    type Err = crate::errors::InnerBoardFromStrError;
+    type Err = InnerBoardFromStrError;
     /// Take the board as a single line string, with each cell represented by a single [`char`].
     /// Empty cells marked by `-`.
     ///
+    /// On failure, the returned [`InnerBoardFromStrError`] points at the offending part of the input.
+    ///
     /// ```
     /// # use tic_tac_toe::{Player, board::inner::InnerBoard};
     /// # use std::str::FromStr;
     /// let board = InnerBoard::from_str("OX-XXXO--").unwrap();
-    /// assert_eq!(board, InnerBoard::from([Some(Player::Circle), Some(Player::Cross), None, Some(Player::Cross), Some(Player::Cross), Some(Player::Cross), Some(Player::Circle), None, None]))
+    /// assert_eq!(board, InnerBoard::from([Some(Player::Circle), Some(Player::Cross), None, Some(Player::Cross), Some(Player::Cross), Some(Player::Cross), Some(Player::Circle), None, None]));
+    ///
+    /// # use tic_tac_toe::errors::InnerBoardFromStrError;
+    /// assert_eq!(
+    ///     InnerBoard::from_str("OX-XXXO-"),
+    ///     Err(InnerBoardFromStrError::InvalidLength { expected: 9, actual: 8 })
+    /// );
+    /// assert_eq!(
+    ///     InnerBoard::from_str("OX-XaXO--"),
+    ///     Err(InnerBoardFromStrError::InvalidChar { index: 4, found: 'a' })
+    /// );
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != 9 {
-            return Err(crate::errors::InnerBoardFromStrError::InvalidLength);
+        let length = s.chars().count();
+        if length != 9 {
+            return Err(InnerBoardFromStrError::InvalidLength {
+                expected: 9,
+                actual: length,
+            });
         }
         let mut board_array = [const { None }; 9];
-        for (i, c) in s.chars().enumerate() {
+        for (cell, (index, c)) in s.char_indices().enumerate() {
             if c == '-' {
                 continue;
             }
-            board_array[i] = Some(Player::try_from(c)?);
+            board_array[cell] = Some(
+                Player::try_from(c)
+                    .map_err(|_| InnerBoardFromStrError::InvalidChar { index, found: c })?,
+            );
         }
 
         Ok(InnerBoard::from(board_array))
@@ -147,4 +539,93 @@ mod tests {
         "
         );
     }
+
+    #[test]
+    fn solve_inner_board() {
+        let finished = InnerBoard::from_str("OX-XXXO--").unwrap();
+        assert_eq!(
+            finished.solve(Player::Circle),
+            BoardResult::Winner(Player::Cross),
+            "Doesn't keep the result of a finished board."
+        );
+
+        let fork = InnerBoard::from_str("XX--O-X--").unwrap();
+        assert_eq!(
+            fork.solve(Player::Circle),
+            BoardResult::Winner(Player::Cross),
+            "Doesn't see that a double threat can't be blocked."
+        );
+
+        let blocked = InnerBoard::from_str("XOX-O----").unwrap();
+        assert_eq!(
+            blocked.solve(Player::Cross),
+            BoardResult::Draw,
+            "Doesn't find the forced draw."
+        );
+    }
+
+    #[test]
+    fn inner_board_from_str_errors() {
+        assert_eq!(
+            InnerBoard::from_str(""),
+            Err(InnerBoardFromStrError::InvalidLength {
+                expected: 9,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            InnerBoard::from_str("OX-XXXO---"),
+            Err(InnerBoardFromStrError::InvalidLength {
+                expected: 9,
+                actual: 10
+            })
+        );
+        // Lengths are counted in characters, not bytes.
+        assert_eq!(
+            InnerBoard::from_str("é-X-O-X-o"),
+            Err(InnerBoardFromStrError::InvalidChar {
+                index: 0,
+                found: 'é'
+            })
+        );
+        assert_eq!(
+            InnerBoard::from_str("OX-XXXO-o"),
+            Err(InnerBoardFromStrError::InvalidChar {
+                index: 8,
+                found: 'o'
+            })
+        );
+    }
+
+    #[test]
+    fn solve_table_matches_finished_boards() {
+        for index in 0..BOARD_COUNT {
+            let board = InnerBoard::from_base3_index(index);
+            assert_eq!(board.base3_index(), index);
+            if let BoardState::Over(result) = board.get_state() {
+                for player in [Player::Cross, Player::Circle] {
+                    assert_eq!(board.solve(player), result, "Disagrees on {board:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn get_states_batch_matches_get_state() {
+        let boards: Vec<InnerBoard> = (0..3usize.pow(9))
+            .map(|mut index| {
+                let mut cells = [None; 9];
+                for cell in &mut cells {
+                    *cell = [None, Some(Player::Circle), Some(Player::Cross)][index % 3];
+                    index /= 3;
+                }
+                InnerBoard::from(cells)
+            })
+            .collect();
+
+        let states = InnerBoard::get_states_batch(&boards);
+        for (board, state) in boards.iter().zip(states) {
+            assert_eq!(board.get_state(), state, "Disagrees on {board:?}");
+        }
+    }
 }
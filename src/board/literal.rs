@@ -0,0 +1,143 @@
+use super::InnerBoard;
+use crate::Player;
+
+/// Builds an [`InnerBoard`](crate::board::InnerBoard) out of a literal with one string per row,
+/// checked at compile time.
+///
+/// Each row must have 3 cells: `X` or `O` (in either case) for marks, and `-`, `.` or `_` for
+/// empty cells. Whitespace and `|` are ignored. Anything else fails to compile. The result is a
+/// constant, so it can also be used in `const` and `static` items.
+///
+/// `-` is an empty cell, like in [`InnerBoard`](crate::board::InnerBoard)'s
+/// [`FromStr`](std::str::FromStr), and not a separator like in
+/// [`RecursiveBoard::from_diagram`](crate::board::RecursiveBoard::from_diagram).
+///
+/// # Examples
+/// ```
+/// # use std::str::FromStr;
+/// use tic_tac_toe::{board, board::InnerBoard};
+///
+/// let board = board!("X O -" "- X -" "- - O");
+/// assert_eq!(board, InnerBoard::from_str("XO--X---O").unwrap());
+///
+/// static EMPTY: InnerBoard = board!("..." "..." "...");
+/// assert_eq!(EMPTY, InnerBoard::new());
+/// ```
+///
+/// ```compile_fail
+/// let board = tic_tac_toe::board!("X O -" "- X" "- - O");
+/// ```
+#[macro_export]
+macro_rules! board {
+    ($($row:literal)+) => {
+        const { $crate::board::InnerBoard::from_cells($crate::board::literal::inner(&[$($row),+])) }
+    };
+}
+
+/// Builds a [`RecursiveBoard`](crate::board::RecursiveBoard) out of a literal with one string per
+/// row of the full 9x9 grid, checked at compile time.
+///
+/// Each row must have 9 cells, written like in [`board!`](crate::board!). As `-` is an empty
+/// cell, rows of dashes can't separate sub-boards like they do in
+/// [`RecursiveBoard::from_diagram`](crate::board::RecursiveBoard::from_diagram). Unlike
+/// [`board!`](crate::board!), the board itself is built at runtime, so this can't be used in
+/// `const` items.
+///
+/// # Examples
+/// ```
+/// use tic_tac_toe::{Player, board, rboard, board::{Board, cell::Cell}};
+///
+/// let board = rboard!(
+///     "X X X | . . . | . . ."
+///     ". . . | . . . | . . ."
+///     ". . . | . . . | . . ."
+///     ". . . | . . . | . . ."
+///     ". . . | . O . | . . ."
+///     ". . . | . . . | . . ."
+///     ". . . | . . . | . . ."
+///     ". . . | . . . | . . ."
+///     ". . . | . . . | . . X"
+/// );
+///
+/// assert_eq!(board.get_cell(0).owner(), Some(&Player::Cross));
+/// assert_eq!(board.get_cell(4).board(), &board!("..." ".O." "..."));
+/// assert_eq!(board.get_cell(8).board().get_cell(8), &Some(Player::Cross));
+/// ```
+///
+/// ```compile_fail
+/// let board = tic_tac_toe::rboard!("X X X | . . . | . . #");
+/// ```
+#[macro_export]
+macro_rules! rboard {
+    ($($row:literal)+) => {
+        $crate::board::RecursiveBoard::from(
+            const { $crate::board::literal::recursive(&[$($row),+]) },
+        )
+    };
+}
+
+/// Reads the 3 rows of an [`InnerBoard`] for [`board!`](crate::board!).
+///
+/// # Panics
+/// This will panic if `rows` is not a valid board literal.
+#[must_use]
+pub const fn inner(rows: &[&str]) -> [Option<Player>; 9] {
+    let mut cells = [None; 9];
+    parse(rows, 3, &mut cells);
+    cells
+}
+
+/// Reads the 9 rows of the full grid for [`rboard!`](crate::rboard!), split into sub-boards.
+///
+/// # Panics
+/// This will panic if `rows` is not a valid board literal.
+#[must_use]
+pub const fn recursive(rows: &[&str]) -> [InnerBoard; 9] {
+    let mut cells = [None; 81];
+    parse(rows, 9, &mut cells);
+
+    let mut boards = [const { InnerBoard::new() }; 9];
+    let mut outer = 0;
+    while outer < 9 {
+        let mut inner = [None; 9];
+        let mut cell = 0;
+        while cell < 9 {
+            let position = super::CellPosition::new(outer, cell);
+            inner[cell] = cells[position.row() * 9 + position.column()];
+            cell += 1;
+        }
+        boards[outer] = InnerBoard::from_cells(inner);
+        outer += 1;
+    }
+    boards
+}
+
+/// Fills `cells` row by row out of `rows`, each of which must have `size` cells.
+const fn parse(rows: &[&str], size: usize, cells: &mut [Option<Player>]) {
+    assert!(rows.len() == size, "wrong number of rows in board literal");
+
+    let mut row = 0;
+    while row < size {
+        let bytes = rows[row].as_bytes();
+        let mut column = 0;
+        let mut index = 0;
+        while index < bytes.len() {
+            let value = match bytes[index] {
+                b'X' | b'x' => Some(Player::Cross),
+                b'O' | b'o' => Some(Player::Circle),
+                b'-' | b'.' | b'_' => None,
+                b'|' | b' ' | b'\t' => {
+                    index += 1;
+                    continue;
+                }
+                _ => panic!("invalid character in board literal"),
+            };
+            assert!(column < size, "too many cells in a row of board literal");
+            cells[row * size + column] = value;
+            column += 1;
+            index += 1;
+        }
+        assert!(column == size, "too few cells in a row of board literal");
+        row += 1;
+    }
+}
@@ -8,16 +8,82 @@ pub mod inner;
 
 pub use inner::InnerBoard;
 
-/// Contains the [`RecursiveBoard`]: the driving type of this module, as it represents the board
+/// Contains the [`CellPosition`], which points at a single cell of the full game, and the
+/// [`Move`] made there.
+pub mod position;
+pub use position::{CellPosition, Move};
 
+/// Contains the [`RecursiveBoard`]: the driving type of this module, as it represents the board
 /// of the Ultimate Tic-Tac-Toe game itself.
 pub mod recursive;
 pub use recursive::RecursiveBoard;
 
+/// Support for the [`board!`](crate::board!) and [`rboard!`](crate::rboard!) macros.
+#[doc(hidden)]
+pub mod literal;
+
+/// Text renderings of the full 9x9 grid of a [`RecursiveBoard`], beyond the outer-board
+/// [`Display`](std::fmt::Display).
+pub mod render;
+
 #[cfg(test)]
 mod tests;
 
-use crate::{BoardResult, BoardState, Player};
+use crate::{BoardResult, BoardState, Player, PlayerLabels};
+
+/// The 8 lines of a 3x3 board, as the indices of their cells: the rows from top to bottom, the
+/// columns from left to right, and then the diagonals from the top-left and from the top-right.
+///
+/// # Examples
+/// ```
+/// use tic_tac_toe::{Player, board::{Board, InnerBoard, WIN_LINES}};
+///
+/// let board = InnerBoard::builder().x(2).x(4).x(6).build();
+/// let lines = WIN_LINES
+///     .iter()
+///     .filter(|line| line.iter().all(|&cell| board.get_cell(cell) == &Some(Player::Cross)));
+///
+/// assert_eq!(lines.collect::<Vec<_>>(), [&[2, 4, 6]]);
+/// ```
+pub const WIN_LINES: [[usize; 3]; 8] = [
+    [0, 1, 2],
+    [3, 4, 5],
+    [6, 7, 8],
+    [0, 3, 6],
+    [1, 4, 7],
+    [2, 5, 8],
+    [0, 4, 8],
+    [2, 4, 6],
+];
+
+/// Returns a mask with a bit set for each cell that would complete one of the [`WIN_LINES`]: the
+/// other two cells of the line are `owned`, and the cell itself is `open`.
+pub(crate) fn threat_mask(owned: impl Fn(usize) -> bool, open: impl Fn(usize) -> bool) -> u16 {
+    let mut mask = 0;
+    for line in WIN_LINES {
+        let owned_count = line.iter().filter(|&&cell| owned(cell)).count();
+        let mut open_cells = line.iter().filter(|&&cell| open(cell));
+        if let (2, Some(&cell), None) = (owned_count, open_cells.next(), open_cells.next()) {
+            mask |= 1 << cell;
+        }
+    }
+    mask
+}
+
+/// Iterates over the cells set in a `mask` of a 3x3 board, in ascending order.
+pub(crate) fn mask_cells(mask: u16) -> impl Iterator<Item = usize> {
+    (0..9).filter(move |cell| mask & 1 << cell != 0)
+}
+
+/// The index of the center cell of a 3x3 board, which is in 4 of the [`WIN_LINES`].
+pub const CENTER: usize = 4;
+
+/// The indices of the corner cells of a 3x3 board, each in 3 of the [`WIN_LINES`].
+pub const CORNERS: [usize; 4] = [0, 2, 6, 8];
+
+/// The indices of the edge cells of a 3x3 board, between two corners. Each is in 2 of the
+/// [`WIN_LINES`].
+pub const EDGES: [usize; 4] = [1, 3, 5, 7];
 
 /// The trait that represents a board. Allows to check for the states of cells, state of the board as a whole etc.
 pub trait Board<T: cell::Cell> {
@@ -96,20 +162,20 @@ pub trait Board<T: cell::Cell> {
         }
 
         // Diagonals: We use the fact that both diagonals intersect the center cell to just check if the extremities are equal to that.
-        let center_cell = self.get_cell(4).owner();
-        if let Some(player) = center_cell {
-            if (center_cell == self.get_cell(0).owner() && center_cell == self.get_cell(8).owner())
+        let center_cell = self.get_cell(CENTER).owner();
+        if let Some(player) = center_cell
+            && ((center_cell == self.get_cell(0).owner()
+                && center_cell == self.get_cell(8).owner())
                 || (center_cell == self.get_cell(2).owner()
-                    && center_cell == self.get_cell(6).owner())
-            {
-                return BoardState::Over(BoardResult::Winner(*player));
-            }
+                    && center_cell == self.get_cell(6).owner()))
+        {
+            return BoardState::Over(BoardResult::Winner(*player));
         }
 
         // Check for a draw
         let mut is_draw = true;
         for cell in 0..9 {
-            if self.get_cell(cell).owner().is_none() {
+            if !self.get_cell(cell).is_decided() {
                 is_draw = false;
                 break;
             }
@@ -120,6 +186,58 @@ pub trait Board<T: cell::Cell> {
 
         BoardState::InProgress
     }
+
+    /// Returns the result of calling `f` on each cell, in index order.
+    ///
+    /// Together with [`FromCells`], this transforms any board cell by cell.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::str::FromStr;
+    /// use tic_tac_toe::{Player, board::{Board, InnerBoard, RecursiveBoard, cell::Cell}};
+    ///
+    /// let board = InnerBoard::from_str("XO-------").unwrap();
+    /// let empty = board.map_cells(|cell| cell.is_none());
+    /// assert_eq!(empty, [false, false, true, true, true, true, true, true, true]);
+    ///
+    /// let owners = RecursiveBoard::new().map_cells(|cell| cell.owner().copied());
+    /// assert_eq!(owners, [None; 9]);
+    /// ```
+    fn map_cells<U>(&self, mut f: impl FnMut(&T) -> U) -> [U; 9]
+    where
+        Self: Sized,
+    {
+        std::array::from_fn(|cell| f(self.get_cell(cell)))
+    }
+}
+
+/// A [`Board`] that can be built out of its 9 cells, so generic algorithms like symmetries can
+/// produce boards of any type.
+///
+/// # Examples
+/// Mirroring any board left to right:
+/// ```
+/// # use std::str::FromStr;
+/// use tic_tac_toe::board::{Board, FromCells, InnerBoard, cell::Cell};
+///
+/// fn mirror<T: Cell + Clone, B: FromCells<T>>(board: &B) -> B {
+///     let cells = (0..9).map(|cell| board.get_cell(cell - cell % 3 + 2 - cell % 3).clone());
+///     B::try_from_cells(cells).unwrap()
+/// }
+///
+/// let board = InnerBoard::from_str("XO-O--X--").unwrap();
+/// assert_eq!(mirror(&board), InnerBoard::from_str("-OX--O--X").unwrap());
+/// ```
+pub trait FromCells<T: cell::Cell>: Board<T> + Sized {
+    /// Returns the board with the given cells, in index order.
+    fn from_cells(cells: [T; 9]) -> Self;
+
+    /// Returns the board with the cells yielded by `cells`, in index order, or [`None`] if it
+    /// doesn't yield exactly 9 of them.
+    fn try_from_cells(cells: impl IntoIterator<Item = T>) -> Option<Self> {
+        let cells: Vec<T> = cells.into_iter().take(10).collect();
+        <[T; 9]>::try_from(cells).ok().map(Self::from_cells)
+    }
 }
 
 /// A trait that implements a default [`fmt`](BoardDisplay::fmt) function that gives a reasonable
@@ -168,6 +286,15 @@ where
     ///
     /// Should be used as a simple redirection in the [`Display`](std::fmt::Display) implementation.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_with_labels(f, &PlayerLabels::default())
+    }
+
+    /// Same as [`BoardDisplay::fmt`], but the [`Player`]s are shown with the marks in `labels`.
+    fn fmt_with_labels(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        labels: &PlayerLabels,
+    ) -> std::fmt::Result {
         const TEMPLATE_STR: &str = " 0 │ 1 │ 2 
 ———————————
  3 │ 4 │ 5 
@@ -175,16 +302,49 @@ where
  6 │ 7 │ 8 \
         ";
 
-        let mut result_str = TEMPLATE_STR.to_string();
+        // Filled in a single pass, so marks that are digits aren't mistaken for cells.
+        let result_str: String = TEMPLATE_STR
+            .chars()
+            .map(|c| match c.to_digit(10) {
+                Some(cell) => {
+                    let cell = self.get_cell(cell as usize);
+                    cell.owner()
+                        .map_or(cell.as_char(), |player| labels.mark(*player))
+                }
+                None => c,
+            })
+            .collect();
 
-        for cell in 0..9 {
-            result_str = result_str.replace(
-                char::from_digit(cell, 10).unwrap(),
-                self.get_cell(cell as usize).as_char().to_string().as_str(),
-            );
+        write!(f, "{result_str}")
+    }
+
+    /// Returns something that [displays](std::fmt::Display) the board like [`BoardDisplay::fmt`], but
+    /// with the marks in `labels`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::str::FromStr;
+    /// use tic_tac_toe::{PlayerLabels, board::{BoardDisplay, InnerBoard}};
+    ///
+    /// let board = InnerBoard::from_str("XO-------").unwrap();
+    /// let labels = PlayerLabels::default().with_marks('1', '2');
+    ///
+    /// assert!(board.with_labels(&labels).to_string().starts_with(" 1 │ 2 │   "));
+    /// ```
+    fn with_labels<'a>(&'a self, labels: &'a PlayerLabels) -> impl std::fmt::Display + 'a
+    where
+        Self: Sized,
+        T: 'a,
+    {
+        struct Labelled<'a, B, T>(&'a B, &'a PlayerLabels, std::marker::PhantomData<T>);
+
+        impl<B: BoardDisplay<T>, T: cell::Cell> std::fmt::Display for Labelled<'_, B, T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt_with_labels(f, self.1)
+            }
         }
 
-        write!(f, "{result_str}")
+        Labelled(self, labels, std::marker::PhantomData)
     }
 }
 
@@ -0,0 +1,545 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    Player,
+    errors::{CellPositionFromStrError, IndexOutOfRange},
+};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+/// The position of a single cell in the full game: the sub-board it's in, and the cell inside of it.
+///
+/// Both indices go from `0` to `8`, left to right and then top to bottom, just like
+/// [`Board::get_cell`](super::Board::get_cell).
+///
+/// Positions can also be written in algebraic notation, which names the full 9x9 grid like a chess
+/// board: columns go from `a` to `i`, left to right, and rows from `1` to `9`, bottom to top. That
+/// makes `a9` the top-left cell and `e5` the center of the center board.
+pub struct CellPosition {
+    outer: OuterIdx,
+    inner: InnerIdx,
+}
+
+impl CellPosition {
+    #[must_use]
+    /// Returns the position of the `inner` cell of the `outer` sub-board.
+    ///
+    /// # Panics
+    /// This will panic if `outer` or `inner` is not less than 9.
+    pub const fn new(outer: usize, inner: usize) -> Self {
+        Self::from_indices(OuterIdx::new(outer), InnerIdx::new(inner))
+    }
+
+    #[must_use]
+    /// Returns the position of the `inner` cell of the `outer` sub-board, with indices that are
+    /// known to be valid and can't be swapped by mistake.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::board::{CellPosition, position::{InnerIdx, OuterIdx}};
+    ///
+    /// let position = CellPosition::from_indices(OuterIdx::CENTER, InnerIdx::new(2));
+    /// assert_eq!(position, CellPosition::new(4, 2));
+    /// assert_eq!(position.outer(), OuterIdx::CENTER);
+    /// assert_eq!(position.inner().get(), 2);
+    /// ```
+    pub const fn from_indices(outer: OuterIdx, inner: InnerIdx) -> Self {
+        Self { outer, inner }
+    }
+
+    #[must_use]
+    /// Returns the index of the sub-board this position is in.
+    pub const fn outer(&self) -> OuterIdx {
+        self.outer
+    }
+
+    #[must_use]
+    /// Returns the index of the cell in its sub-board.
+    pub const fn inner(&self) -> InnerIdx {
+        self.inner
+    }
+
+    #[must_use]
+    /// Returns the position at `row` and `column` of the full 9x9 grid, counting from the top-left.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::board::CellPosition;
+    ///
+    /// assert_eq!(CellPosition::from_grid(0, 0), CellPosition::new(0, 0));
+    /// assert_eq!(CellPosition::from_grid(4, 4), CellPosition::new(4, 4));
+    /// assert_eq!(CellPosition::from_grid(3, 8), CellPosition::new(5, 2));
+    /// ```
+    pub const fn from_grid(row: usize, column: usize) -> Self {
+        Self::new((row / 3) * 3 + column / 3, (row % 3) * 3 + column % 3)
+    }
+
+    #[must_use]
+    /// Returns the row of the full 9x9 grid this position is in, counting from the top.
+    pub const fn row(&self) -> usize {
+        self.outer.row() * 3 + self.inner.row()
+    }
+
+    #[must_use]
+    /// Returns the column of the full 9x9 grid this position is in, counting from the left.
+    pub const fn column(&self) -> usize {
+        self.outer.column() * 3 + self.inner.column()
+    }
+
+    #[must_use]
+    /// Returns the index of this position among all 81 cells, `outer * 9 + inner`. This is the
+    /// order of the bits in [`CellPosition::bit`].
+    pub const fn index(&self) -> usize {
+        self.outer.get() * 9 + self.inner.get()
+    }
+
+    #[must_use]
+    /// Returns the position with the given [`index`](CellPosition::index).
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::board::CellPosition;
+    ///
+    /// assert_eq!(CellPosition::from_index(40), CellPosition::new(4, 4));
+    /// assert_eq!(CellPosition::from_index(80).index(), 80);
+    /// ```
+    pub const fn from_index(index: usize) -> Self {
+        Self::new(index / 9, index % 9)
+    }
+
+    #[must_use]
+    /// Returns a mask of the 81 cells with only this position's bit set, the bit being its
+    /// [`index`](CellPosition::index).
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::board::CellPosition;
+    ///
+    /// let position = CellPosition::new(4, 4);
+    /// assert_eq!(position.bit(), 1 << 40);
+    /// assert_eq!(CellPosition::from_index(position.bit().trailing_zeros() as usize), position);
+    /// ```
+    pub const fn bit(&self) -> u128 {
+        1 << self.index()
+    }
+
+    #[must_use]
+    /// Returns the algebraic name of this position. This is also how it is [displayed](Display).
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::board::CellPosition;
+    ///
+    /// assert_eq!(CellPosition::new(0, 0).to_algebraic(), "a9");
+    /// assert_eq!(CellPosition::new(4, 4).to_algebraic(), "e5");
+    /// assert_eq!(CellPosition::new(6, 7).to_algebraic(), "b1");
+    /// ```
+    pub fn to_algebraic(&self) -> String {
+        let column = char::from(b'a' + self.column() as u8);
+        let row = char::from(b'9' - self.row() as u8);
+        format!("{column}{row}")
+    }
+
+    /// Reads a position from its algebraic name. The column may be in either case.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::board::CellPosition;
+    ///
+    /// assert_eq!(CellPosition::from_algebraic("e5"), Ok(CellPosition::new(4, 4)));
+    /// assert_eq!(CellPosition::from_algebraic("C7"), Ok(CellPosition::new(0, 8)));
+    /// assert!(CellPosition::from_algebraic("j1").is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`CellPositionFromStrError`] if `s` isn't a column letter followed by a row digit.
+    pub fn from_algebraic(s: &str) -> Result<Self, CellPositionFromStrError> {
+        let mut chars = s.chars();
+        let (Some(column), Some(row), None) = (chars.next(), chars.next(), chars.next()) else {
+            return Err(CellPositionFromStrError::InvalidLength);
+        };
+
+        let column = match column.to_ascii_lowercase() {
+            c @ 'a'..='i' => c as usize - 'a' as usize,
+            _ => return Err(CellPositionFromStrError::InvalidColumn { found: column }),
+        };
+        let row = match row {
+            '1'..='9' => '9' as usize - row as usize,
+            _ => return Err(CellPositionFromStrError::InvalidRow { found: row }),
+        };
+
+        Ok(Self::from_grid(row, column))
+    }
+
+    #[must_use]
+    /// Returns the [`Move`] of `player` placing their mark at this position.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{CellPosition, Move}};
+    ///
+    /// let position = CellPosition::new(4, 4);
+    /// assert_eq!(
+    ///     position.by(Player::Cross),
+    ///     Move { position, player: Player::Cross }
+    /// );
+    /// ```
+    pub const fn by(self, player: Player) -> Move {
+        Move {
+            position: self,
+            player,
+        }
+    }
+
+    #[must_use]
+    /// Returns the keypad name of this position: the key of the sub-board followed by the key of
+    /// the cell inside of it, both in `layout`.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::board::{CellPosition, position::KeypadLayout};
+    ///
+    /// assert_eq!(CellPosition::new(4, 4).to_keypad(KeypadLayout::Phone), "55");
+    /// assert_eq!(CellPosition::new(0, 2).to_keypad(KeypadLayout::Phone), "13");
+    /// assert_eq!(CellPosition::new(0, 2).to_keypad(KeypadLayout::Numpad), "79");
+    /// ```
+    pub fn to_keypad(&self, layout: KeypadLayout) -> String {
+        format!(
+            "{}{}",
+            layout.key(self.outer.get()),
+            layout.key(self.inner.get())
+        )
+    }
+
+    /// Reads a position from its [keypad name](CellPosition::to_keypad) in `layout`.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::board::{CellPosition, position::KeypadLayout};
+    ///
+    /// assert_eq!(
+    ///     CellPosition::from_keypad("19", KeypadLayout::Phone),
+    ///     Ok(CellPosition::new(0, 8))
+    /// );
+    /// assert_eq!(
+    ///     CellPosition::from_keypad("19", KeypadLayout::Numpad),
+    ///     Ok(CellPosition::new(6, 2))
+    /// );
+    /// assert!(CellPosition::from_keypad("50", KeypadLayout::Phone).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`CellPositionFromStrError`] if `s` isn't two digits from `1` to `9`.
+    pub fn from_keypad(s: &str, layout: KeypadLayout) -> Result<Self, CellPositionFromStrError> {
+        let mut chars = s.chars();
+        let (Some(outer), Some(inner), None) = (chars.next(), chars.next(), chars.next()) else {
+            return Err(CellPositionFromStrError::InvalidKeypadLength);
+        };
+
+        let index = |key| {
+            layout
+                .index(key)
+                .ok_or(CellPositionFromStrError::InvalidKey { found: key })
+        };
+        Ok(Self::new(index(outer)?, index(inner)?))
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Default)]
+/// How the keys `1` to `9` are laid out over the cells of a 3x3 board, for naming positions by
+/// the keys people use to label tic-tac-toe cells.
+pub enum KeypadLayout {
+    #[default]
+    /// Like a phone: `1 2 3` on the top row and `7 8 9` on the bottom one.
+    Phone,
+    /// Like a computer's numeric keypad: `7 8 9` on the top row and `1 2 3` on the bottom one.
+    Numpad,
+}
+
+impl KeypadLayout {
+    #[must_use]
+    /// Returns the key of the cell at `index` of a 3x3 board.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::board::position::KeypadLayout;
+    ///
+    /// assert_eq!(KeypadLayout::Phone.key(0), '1');
+    /// assert_eq!(KeypadLayout::Numpad.key(0), '7');
+    /// assert_eq!(KeypadLayout::Numpad.key(8), '3');
+    /// ```
+    pub const fn key(self, index: usize) -> char {
+        debug_assert!(index < 9);
+        let key = match self {
+            Self::Phone => index,
+            Self::Numpad => (2 - index / 3) * 3 + index % 3,
+        };
+        (b'1' + key as u8) as char
+    }
+
+    #[must_use]
+    /// Returns the index of the cell labelled with `key`, or [`None`] if it isn't a digit from
+    /// `1` to `9`.
+    pub const fn index(self, key: char) -> Option<usize> {
+        let key = match key {
+            '1'..='9' => key as usize - '1' as usize,
+            _ => return None,
+        };
+        // Both layouts are their own inverse.
+        Some(match self {
+            Self::Phone => key,
+            Self::Numpad => (2 - key / 3) * 3 + key % 3,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CellPosition {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(u.int_in_range(0..=8)?, u.int_in_range(0..=8)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(
+            <u8 as arbitrary::Arbitrary>::size_hint(depth),
+            <u8 as arbitrary::Arbitrary>::size_hint(depth),
+        )
+    }
+}
+
+impl Display for CellPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_algebraic())
+    }
+}
+
+impl FromStr for CellPosition {
+    type Err = CellPositionFromStrError;
+    /// Same as [`CellPosition::from_algebraic`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_algebraic(s)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+/// A single move of the game: the [`Player`] who made it, and where they placed their mark.
+///
+/// Where the player is implied, a [`CellPosition`] can be turned into a [`Move`] with
+/// [`CellPosition::by`].
+pub struct Move {
+    /// Where the mark was placed.
+    pub position: CellPosition,
+    /// Who placed the mark.
+    pub player: Player,
+}
+
+impl From<(CellPosition, Player)> for Move {
+    fn from((position, player): (CellPosition, Player)) -> Self {
+        position.by(player)
+    }
+}
+
+/// Defines a newtype over a cell index of a 3x3 board, which is always from `0` to `8`.
+macro_rules! index_type {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+        pub struct $name(u8);
+
+        impl $name {
+            /// The index of the center.
+            pub const CENTER: Self = Self(4);
+
+            #[must_use]
+            /// Returns the index `index`.
+            ///
+            /// # Panics
+            /// This will panic if `index` is not less than 9. Use the [`TryFrom`] implementation
+            /// for indices that aren't known to be valid.
+            pub const fn new(index: usize) -> Self {
+                assert!(index < 9, "index out of range");
+                Self(index as u8)
+            }
+
+            #[must_use]
+            /// Returns the index at `row` and `column` of the 3x3 board, each from `0` to `2`.
+            ///
+            /// # Panics
+            /// This will panic if `row` or `column` is not less than 3.
+            pub const fn from_row_column(row: usize, column: usize) -> Self {
+                assert!(row < 3 && column < 3, "row or column out of range");
+                Self::new(row * 3 + column)
+            }
+
+            #[must_use]
+            /// Returns the index as a [`usize`], for the APIs that take raw indices.
+            pub const fn get(self) -> usize {
+                self.0 as usize
+            }
+
+            #[must_use]
+            /// Returns the row of the 3x3 board this index is in, counting from the top.
+            pub const fn row(self) -> usize {
+                self.get() / 3
+            }
+
+            #[must_use]
+            /// Returns the column of the 3x3 board this index is in, counting from the left.
+            pub const fn column(self) -> usize {
+                self.get() % 3
+            }
+
+            /// Returns all 9 indices, in order.
+            pub fn all() -> impl Iterator<Item = Self> {
+                (0..9).map(Self)
+            }
+        }
+
+        impl TryFrom<usize> for $name {
+            type Error = IndexOutOfRange;
+
+            fn try_from(index: usize) -> Result<Self, Self::Error> {
+                if index < 9 {
+                    Ok(Self(index as u8))
+                } else {
+                    Err(IndexOutOfRange { index })
+                }
+            }
+        }
+
+        impl From<$name> for usize {
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+index_type! {
+    /// The index of a sub-board in the outer board, from `0` to `8`. Having a separate type from
+    /// [`InnerIdx`] keeps the two from being passed in place of each other.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::board::position::OuterIdx;
+    ///
+    /// assert_eq!(OuterIdx::try_from(7).map(OuterIdx::row), Ok(2));
+    /// assert!(OuterIdx::try_from(9).is_err());
+    /// assert_eq!(OuterIdx::all().count(), 9);
+    /// ```
+    OuterIdx
+}
+
+index_type! {
+    /// The index of a cell in a sub-board, from `0` to `8`. See [`OuterIdx`].
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::board::position::InnerIdx;
+    ///
+    /// assert_eq!(InnerIdx::from_row_column(1, 2), InnerIdx::new(5));
+    /// assert_eq!(usize::from(InnerIdx::CENTER), 4);
+    /// ```
+    InnerIdx
+}
+
+impl InnerIdx {
+    #[must_use]
+    /// Returns the sub-board a move in this cell sends the opponent to, the one at the same index
+    /// of the outer board.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::board::position::{InnerIdx, OuterIdx};
+    ///
+    /// assert_eq!(InnerIdx::new(2).sends_to(), OuterIdx::new(2));
+    /// ```
+    pub const fn sends_to(self) -> OuterIdx {
+        OuterIdx(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn algebraic_round_trip() {
+        for outer in 0..9 {
+            for inner in 0..9 {
+                let position = CellPosition::new(outer, inner);
+                assert_eq!(
+                    CellPosition::from_grid(position.row(), position.column()),
+                    position
+                );
+                assert_eq!(
+                    CellPosition::from_algebraic(&position.to_algebraic()),
+                    Ok(position)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn index_types_match_positions() {
+        for outer in OuterIdx::all() {
+            for inner in InnerIdx::all() {
+                let position = CellPosition::from_indices(outer, inner);
+                assert_eq!((position.outer(), position.inner()), (outer, inner));
+                assert_eq!(
+                    position.row(),
+                    outer.row() * 3 + inner.row(),
+                    "{outer} {inner}"
+                );
+                assert_eq!(position.column(), outer.column() * 3 + inner.column());
+            }
+        }
+        assert_eq!(InnerIdx::try_from(12), Err(IndexOutOfRange { index: 12 }));
+    }
+
+    #[test]
+    fn keypad_round_trip() {
+        for layout in [KeypadLayout::Phone, KeypadLayout::Numpad] {
+            for index in 0..81 {
+                let position = CellPosition::from_index(index);
+                assert_eq!(
+                    CellPosition::from_keypad(&position.to_keypad(layout), layout),
+                    Ok(position)
+                );
+            }
+        }
+        assert_eq!(
+            CellPosition::from_keypad("5", KeypadLayout::Phone),
+            Err(CellPositionFromStrError::InvalidKeypadLength)
+        );
+        assert_eq!(
+            CellPosition::from_keypad("5a", KeypadLayout::Numpad),
+            Err(CellPositionFromStrError::InvalidKey { found: 'a' })
+        );
+    }
+
+    #[test]
+    fn algebraic_errors() {
+        assert_eq!(
+            CellPosition::from_algebraic("e"),
+            Err(CellPositionFromStrError::InvalidLength)
+        );
+        assert_eq!(
+            CellPosition::from_algebraic("e55"),
+            Err(CellPositionFromStrError::InvalidLength)
+        );
+        assert_eq!(
+            CellPosition::from_algebraic("j5"),
+            Err(CellPositionFromStrError::InvalidColumn { found: 'j' })
+        );
+        assert_eq!(
+            CellPosition::from_algebraic("e0"),
+            Err(CellPositionFromStrError::InvalidRow { found: '0' })
+        );
+    }
+}
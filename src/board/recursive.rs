@@ -1,12 +1,21 @@
-use std::fmt::Display;
+use std::fmt::{Debug, Display};
 
-use crate::{BoardResult, BoardState};
+use crate::{
+    BoardResult, BoardState, Player, PlayerLabels,
+    errors::{DiagramError, SetCellError},
+};
 
-use super::{Board, BoardDisplay, cell::Cell, inner::InnerBoard};
-pub use cell::RecursiveCell;
+use super::{
+    Board, BoardDisplay, CellPosition, Move, WIN_LINES,
+    cell::{Cell, CellMut},
+    inner::InnerBoard,
+    position::{InnerIdx, OuterIdx},
+};
+pub use cell::{RecursiveCell, SubBoardSummary};
 
 /// A game board that contains game boards of itself. Each cell is stored as a [`RecursiveCell`], which
 /// then contains the [`InnerBoard`] for that cell.
+#[derive(Clone)]
 pub struct RecursiveBoard {
     cells: [RecursiveCell; 9],
 }
@@ -19,14 +28,872 @@ impl RecursiveBoard {
             cells: [const { RecursiveCell::new() }; 9],
         }
     }
+
+    #[must_use]
+    /// Returns a board where each sub-board is won by the given [`Player`], or empty for [`None`].
+    /// Usable in `const` contexts, so fixtures can be kept in `static`s.
+    ///
+    /// Only the outcome of the sub-boards is given, so each won sub-board is filled with its
+    /// owner's marks on the top row.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{BoardResult, BoardState, Player, board::{Board, RecursiveBoard}};
+    ///
+    /// const X: Option<Player> = Some(Player::Cross);
+    /// static BOARD: RecursiveBoard =
+    ///     RecursiveBoard::from_owners([X, None, None, None, X, None, None, None, X]);
+    ///
+    /// assert_eq!(BOARD.get_state(), BoardState::Over(BoardResult::Winner(Player::Cross)));
+    /// ```
+    pub const fn from_owners(owners: [Option<Player>; 9]) -> Self {
+        let mut cells = [const { RecursiveCell::new() }; 9];
+        let mut cell = 0;
+        while cell < 9 {
+            if let Some(player) = owners[cell] {
+                cells[cell] = RecursiveCell::won_by(player);
+            }
+            cell += 1;
+        }
+        Self { cells }
+    }
+
+    #[must_use]
+    /// Returns a [`RecursiveBoardBuilder`] for an empty board, to set it up sub-board by
+    /// sub-board.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{Board, InnerBoard, RecursiveBoard, cell::Cell}};
+    ///
+    /// let board = RecursiveBoard::builder()
+    ///     .sub_board(3, InnerBoard::builder().x(0).o(4).build())
+    ///     .owner(6, Player::Cross)
+    ///     .mark(4, 4, Player::Circle)
+    ///     .build();
+    ///
+    /// assert_eq!(board.get_cell(3).board(), &InnerBoard::builder().x(0).o(4).build());
+    /// assert_eq!(board.get_cell(6).owner(), Some(&Player::Cross));
+    /// assert_eq!(board.get_cell(4).board().get_cell(4), &Some(Player::Circle));
+    /// ```
+    pub const fn builder() -> RecursiveBoardBuilder {
+        RecursiveBoardBuilder { board: Self::new() }
+    }
+
+    /// Returns a mutable iterator over the sub-boards, in index order. They can be changed through
+    /// [`CellMut`] or replaced whole.
+    pub fn cells_mut(&mut self) -> std::slice::IterMut<'_, RecursiveCell> {
+        self.cells.iter_mut()
+    }
+
+    /// Places `player`'s mark in the `inner` cell of the `outer` sub-board, as long as the rules allow it:
+    /// the sub-board must still be in progress and the cell must be empty.
+    ///
+    /// This doesn't know whose turn it is nor which sub-board the move was sent to, so those
+    /// are left to the caller.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{Board, RecursiveBoard}, errors::SetCellError};
+    ///
+    /// let mut board = RecursiveBoard::new();
+    /// assert_eq!(board.try_set_cell(4, 0, Player::Cross), Ok(()));
+    /// assert_eq!(board.get_cell(4).board().get_cell(0), &Some(Player::Cross));
+    ///
+    /// assert_eq!(
+    ///     board.try_set_cell(4, 0, Player::Circle),
+    ///     Err(SetCellError::CellOccupied { outer: 4, inner: 0 })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`SetCellError`] if the `outer` sub-board is already decided, or if the cell
+    /// already has a mark. The board is left unchanged.
+    ///
+    /// # Panics
+    /// This will panic if `outer` or `inner` are not inside their boards.
+    pub fn try_set_cell(
+        &mut self,
+        outer: usize,
+        inner: usize,
+        player: Player,
+    ) -> Result<(), SetCellError> {
+        check_index!("RecursiveBoard", outer);
+        let cell = &self.cells[outer];
+        if cell.state != BoardState::InProgress {
+            return Err(SetCellError::BoardDecided { outer });
+        }
+        if cell.board().get_cell(inner).is_some() {
+            return Err(SetCellError::CellOccupied { outer, inner });
+        }
+        self.set_cell_unchecked(outer, inner, Some(player));
+        Ok(())
+    }
+
+    /// Same as [`RecursiveBoard::try_set_cell`], but the indices are typed, so the sub-board and
+    /// the cell can't be swapped by mistake.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{Board, RecursiveBoard, position::{InnerIdx, OuterIdx}}};
+    ///
+    /// let mut board = RecursiveBoard::new();
+    /// let outer = OuterIdx::new(2);
+    /// assert_eq!(board.try_set_cell_at(outer, InnerIdx::CENTER, Player::Cross), Ok(()));
+    /// assert_eq!(board.sub_board_at(outer).board().get_cell(4), &Some(Player::Cross));
+    /// ```
+    ///
+    /// # Errors
+    /// Same as [`RecursiveBoard::try_set_cell`].
+    pub fn try_set_cell_at(
+        &mut self,
+        outer: OuterIdx,
+        inner: InnerIdx,
+        player: Player,
+    ) -> Result<(), SetCellError> {
+        self.try_set_cell(outer.get(), inner.get(), player)
+    }
+
+    #[must_use]
+    /// Returns the `outer` sub-board. Same as [`Board::get_cell`], but with a typed index that
+    /// can't be out of bounds.
+    pub const fn sub_board_at(&self, outer: OuterIdx) -> &RecursiveCell {
+        &self.cells[outer.get()]
+    }
+
+    /// Iterates over all 81 cells of the full 9x9 grid in row-major order: the whole top row of the
+    /// grid, left to right, then the next one, and so on. This crosses sub-boards, unlike going
+    /// through each sub-board in turn.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{CellPosition, RecursiveBoard}};
+    ///
+    /// let mut board = RecursiveBoard::new();
+    /// board.try_set_cell(1, 0, Player::Cross).unwrap();
+    ///
+    /// let cells: Vec<_> = board.flat_cells().collect();
+    /// assert_eq!(cells.len(), 81);
+    /// assert_eq!(cells[1], (CellPosition::new(0, 1), None));
+    /// assert_eq!(cells[3], (CellPosition::new(1, 0), Some(Player::Cross)));
+    /// assert_eq!(cells[9], (CellPosition::new(0, 3), None));
+    /// ```
+    pub fn flat_cells(&self) -> impl Iterator<Item = (CellPosition, Option<Player>)> + '_ {
+        (0..81).map(|index| {
+            let position = CellPosition::from_grid(index / 9, index % 9);
+            let value = *self
+                .sub_board_at(position.outer())
+                .board()
+                .cell(position.inner());
+            (position, value)
+        })
+    }
+
+    #[must_use]
+    /// Returns a [`CompressedKey`] for this board, where only the outcome of decided sub-boards is
+    /// kept. Under the standard rules, decided sub-boards can't be played in anymore, so boards that
+    /// only differ in their layouts share a key and behave the same from then on.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::str::FromStr;
+    /// use tic_tac_toe::board::{InnerBoard, RecursiveBoard};
+    ///
+    /// let mut boards = [const { InnerBoard::new() }; 9];
+    /// boards[0] = InnerBoard::from_str("XXXOO----").unwrap();
+    /// let row = RecursiveBoard::from(boards.clone());
+    ///
+    /// boards[0] = InnerBoard::from_str("XO-XO-X--").unwrap();
+    /// let column = RecursiveBoard::from(boards.clone());
+    ///
+    /// boards[0] = InnerBoard::from_str("XO-XO----").unwrap();
+    /// let in_progress = RecursiveBoard::from(boards);
+    ///
+    /// assert_eq!(row.compressed_key(), column.compressed_key());
+    /// assert_ne!(row.compressed_key(), in_progress.compressed_key());
+    /// ```
+    pub fn compressed_key(&self) -> CompressedKey {
+        let mut key = CompressedKey([0; 3]);
+        let mut offset = 0;
+        for cell in &self.cells {
+            let (tag, payload, payload_bits) = match &cell.state {
+                BoardState::Over(BoardResult::Winner(Player::Cross)) => (1, 0, 0),
+                BoardState::Over(BoardResult::Winner(Player::Circle)) => (2, 0, 0),
+                BoardState::Over(BoardResult::Draw) => (3, 0, 0),
+                BoardState::InProgress => (
+                    0,
+                    cell.board().base3_index() as u64,
+                    CompressedKey::IN_PROGRESS_BITS,
+                ),
+            };
+            key.push(&mut offset, tag, 2);
+            key.push(&mut offset, payload, payload_bits);
+        }
+        key
+    }
+
+    #[must_use]
+    /// Returns a mask with a bit set for each cell where [`RecursiveBoard::try_set_cell`] would
+    /// place a mark: the empty cells of sub-boards still in progress. See [`CellPosition::bit`]
+    /// for the order of the bits.
+    ///
+    /// Which sub-board the next move is sent to isn't known by the board, so that is left to the
+    /// caller to mask out.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{CellPosition, RecursiveBoard}};
+    ///
+    /// let mut board = RecursiveBoard::new();
+    /// assert_eq!(board.playable_mask().count_ones(), 81);
+    ///
+    /// let center = CellPosition::new(4, 4);
+    /// board.try_play(center.by(Player::Cross)).unwrap();
+    /// assert_eq!(board.playable_mask() & center.bit(), 0);
+    /// assert_eq!(board.playable_mask().count_ones(), 80);
+    /// ```
+    pub fn playable_mask(&self) -> u128 {
+        let mut mask = 0;
+        for (outer, cell) in self.cells.iter().enumerate() {
+            if cell.state != BoardState::InProgress {
+                continue;
+            }
+            for inner in 0..9 {
+                if cell.board().get_cell(inner).is_none() {
+                    mask |= CellPosition::new(outer, inner).bit();
+                }
+            }
+        }
+        mask
+    }
+
+    /// Plays `mv` on the board. Same as [`RecursiveBoard::try_set_cell`] with the move's position and player.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{Board, CellPosition, RecursiveBoard}};
+    ///
+    /// let mut board = RecursiveBoard::new();
+    /// let center = CellPosition::new(4, 4);
+    ///
+    /// assert!(board.try_play(center.by(Player::Cross)).is_ok());
+    /// assert!(board.try_play(center.by(Player::Circle)).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`SetCellError`] if the move isn't allowed by the rules.
+    pub fn try_play(&mut self, mv: Move) -> Result<(), SetCellError> {
+        self.try_set_cell_at(mv.position.outer(), mv.position.inner(), mv.player)
+    }
+
+    /// Sets the `inner` cell of the `outer` sub-board to the provided value, without checking
+    /// whether the rules allow it. Overwrites existing marks and writes into decided sub-boards.
+    ///
+    /// Meant for engine internals and position setup, where the move is known to be valid or
+    /// has to be undone. Use [`RecursiveBoard::try_set_cell`] otherwise.
+    ///
+    /// # Panics
+    /// This will panic if `outer` or `inner` are not inside their boards.
+    pub fn set_cell_unchecked(&mut self, outer: usize, inner: usize, value: Option<Player>) {
+        check_index!("RecursiveBoard", outer);
+        self.cells[outer].set_cell(inner, value);
+    }
+
+    /// Reads a board from a 9x9 text diagram, as people usually draw it when sharing positions.
+    ///
+    /// Each line with cells in it is a row of the full grid, from top to bottom. Cells are
+    /// `X`/`O` (in either case) for marks and `.` or `_` for empty cells. Whitespace and the
+    /// separators `|`, `!`, `-`, `=`, `+`, `│`, `─`, `—` and `┼` can go anywhere and are ignored,
+    /// so lines that only separate rows are skipped and the alignment doesn't matter. The
+    /// `RecursiveBoard {` and `}` lines around the alternate [`Debug`] output are skipped too.
+    ///
+    /// Unlike [`InnerBoard`]'s [`FromStr`](std::str::FromStr) and the [`rboard!`](crate::rboard!)
+    /// macro, `-` is a separator here rather than an empty cell, so that rows of dashes can split
+    /// the sub-boards.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{Board, RecursiveBoard, cell::Cell}};
+    ///
+    /// let board = RecursiveBoard::from_diagram(
+    ///     "
+    ///     X X X | . . . | . . .
+    ///     . . . | . O . | . . .
+    ///     . . . | . . . | . . .
+    ///     ------+-------+------
+    ///     . . . | . . . | . . .
+    ///     .  .  .|. . .|. . o
+    ///     . . . | . . . | . . .
+    ///     ------+-------+------
+    ///     ...|...|...
+    ///     ...|...|...
+    ///     ...|...|...
+    ///     ",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(board.get_cell(0).owner(), Some(&Player::Cross));
+    /// assert_eq!(board.get_cell(1).board().get_cell(4), &Some(Player::Circle));
+    /// assert_eq!(board.get_cell(5).board().get_cell(5), &Some(Player::Circle));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`DiagramError`] pointing at the problem if the diagram has unknown characters, or
+    /// if it isn't made out of exactly nine rows of nine cells.
+    pub fn from_diagram(diagram: &str) -> Result<Self, DiagramError> {
+        const SEPARATORS: [char; 9] = ['|', '!', '-', '=', '+', '│', '─', '—', '┼'];
+
+        let mut boards = [const { InnerBoard::new() }; 9];
+        let mut row = 0;
+        for (line_index, line) in diagram.lines().enumerate() {
+            if matches!(line.trim(), "RecursiveBoard {" | "}") {
+                continue;
+            }
+            let mut column = 0;
+            for (char_index, c) in line.chars().enumerate() {
+                let value = match c {
+                    'X' | 'x' => Some(Player::Cross),
+                    'O' | 'o' => Some(Player::Circle),
+                    '.' | '_' => None,
+                    c if c.is_whitespace() || SEPARATORS.contains(&c) => continue,
+                    found => {
+                        return Err(DiagramError::InvalidChar {
+                            line: line_index,
+                            column: char_index,
+                            found,
+                        });
+                    }
+                };
+                if row < 9 && column < 9 {
+                    boards[(row / 3) * 3 + column / 3].set_cell((row % 3) * 3 + column % 3, value);
+                }
+                column += 1;
+            }
+
+            match column {
+                0 => {}
+                9 => row += 1,
+                cells => {
+                    return Err(DiagramError::InvalidRowLength {
+                        line: line_index,
+                        cells,
+                    });
+                }
+            }
+        }
+
+        if row != 9 {
+            return Err(DiagramError::InvalidRowCount { rows: row });
+        }
+        Ok(Self::from(boards))
+    }
+
+    #[must_use]
+    /// Returns the sub-boards that would complete a line on the outer board if `player` won them,
+    /// along with the cells in each of them that would win it right away.
+    ///
+    /// The threats are ordered by sub-board. Sub-boards that `player` can't win anymore are left
+    /// out: decided ones, and those where each line has a mark of the opponent.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::str::FromStr;
+    /// use tic_tac_toe::{Player, board::{InnerBoard, RecursiveBoard, recursive::OuterThreat}};
+    ///
+    /// let mut boards = [const { InnerBoard::new() }; 9];
+    /// boards[0] = InnerBoard::from_str("XXX------").unwrap();
+    /// boards[1] = InnerBoard::from_str("XXX------").unwrap();
+    /// boards[2] = InnerBoard::from_str("X-O-X-O--").unwrap();
+    ///
+    /// let board = RecursiveBoard::from(boards);
+    /// assert_eq!(
+    ///     board.outer_threats(Player::Cross),
+    ///     vec![OuterThreat { outer: 2, winning_cells: vec![8] }]
+    /// );
+    /// assert_eq!(board.outer_threats(Player::Circle), vec![]);
+    /// ```
+    pub fn outer_threats(&self, player: Player) -> Vec<OuterThreat> {
+        let threatened = super::threat_mask(
+            |outer| self.cells[outer].owner() == Some(&player),
+            |outer| {
+                let cell = &self.cells[outer];
+                cell.state == BoardState::InProgress && cell.board().can_be_won_by(player)
+            },
+        );
+
+        super::mask_cells(threatened)
+            .map(|outer| OuterThreat {
+                outer,
+                winning_cells: self.cells[outer].board().winning_cells(player).collect(),
+            })
+            .collect()
+    }
+
+    /// Replaces the whole `outer` sub-board with `board`, without checking whether the rules allow
+    /// it, like [`RecursiveBoard::set_cell_unchecked`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::str::FromStr;
+    /// use tic_tac_toe::{Player, board::{Board, InnerBoard, RecursiveBoard, cell::Cell}};
+    ///
+    /// let mut board = RecursiveBoard::new();
+    /// board.fill_sub_board(4, InnerBoard::from_str("OOO-XX---").unwrap());
+    /// assert_eq!(board.get_cell(4).owner(), Some(&Player::Circle));
+    /// ```
+    ///
+    /// # Panics
+    /// This will panic if `outer` is not inside the board.
+    pub fn fill_sub_board(&mut self, outer: usize, board: InnerBoard) {
+        check_index!("RecursiveBoard", outer);
+        self.cells[outer] = RecursiveCell::from(board);
+    }
+
+    #[must_use]
+    /// Returns how many sub-boards have been won by [`Player::Cross`] and [`Player::Circle`], in
+    /// that order. Drawn sub-boards count for neither.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::RecursiveBoard};
+    ///
+    /// let (x, o) = (Some(Player::Cross), Some(Player::Circle));
+    /// let board = RecursiveBoard::from_owners([x, o, x, None, x, None, None, None, None]);
+    ///
+    /// assert_eq!(board.score(), (3, 1));
+    /// ```
+    pub fn score(&self) -> (u8, u8) {
+        let (mut crosses, mut circles) = (0, 0);
+        for cell in &self.cells {
+            match cell.owner() {
+                Some(Player::Cross) => crosses += 1,
+                Some(Player::Circle) => circles += 1,
+                None => {}
+            }
+        }
+        (crosses, circles)
+    }
+
+    #[must_use]
+    /// Returns the state of the outer board like [`Board::get_state`], but settling drawn games by
+    /// the given [`TieBreak`] rule. Sub-boards are not affected.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{
+    ///     BoardResult, BoardState, Player,
+    ///     board::{Board, RecursiveBoard, recursive::TieBreak},
+    /// };
+    ///
+    /// let (x, o) = (Some(Player::Cross), Some(Player::Circle));
+    /// let board = RecursiveBoard::from_owners([x, o, x, x, o, o, o, x, x]);
+    ///
+    /// assert_eq!(board.get_state(), BoardState::Over(BoardResult::Draw));
+    /// assert_eq!(board.get_state_with_tie_break(TieBreak::Draw), board.get_state());
+    /// assert_eq!(
+    ///     board.get_state_with_tie_break(TieBreak::MostBoards),
+    ///     BoardState::Over(BoardResult::Winner(Player::Cross))
+    /// );
+    /// ```
+    pub fn get_state_with_tie_break(&self, tie_break: TieBreak) -> BoardState {
+        let state = self.get_state();
+        if state != BoardState::Over(BoardResult::Draw) || tie_break == TieBreak::Draw {
+            return state;
+        }
+
+        let (crosses, circles) = self.score();
+        match crosses.cmp(&circles) {
+            std::cmp::Ordering::Greater => BoardState::Over(BoardResult::Winner(Player::Cross)),
+            std::cmp::Ordering::Less => BoardState::Over(BoardResult::Winner(Player::Circle)),
+            std::cmp::Ordering::Equal => state,
+        }
+    }
+
+    #[must_use]
+    /// Describes the board in plain English, for screen readers and voice interfaces: who won which
+    /// sub-boards, which are drawn, whether the game is over and, if not, which lines of the outer
+    /// board each player is one sub-board away from.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::RecursiveBoard};
+    ///
+    /// let (x, o) = (Some(Player::Cross), Some(Player::Circle));
+    /// let board = RecursiveBoard::from_owners([None, x, None, None, x, None, None, None, o]);
+    ///
+    /// assert_eq!(
+    ///     board.describe(),
+    ///     "X has won the top and center boards. O has won the bottom-right board. \
+    ///      X threatens the middle column of the main board."
+    /// );
+    /// ```
+    pub fn describe(&self) -> String {
+        self.describe_with_labels(&PlayerLabels::default())
+    }
+
+    #[must_use]
+    /// Same as [`RecursiveBoard::describe`], but the [`Player`]s are called by their names in
+    /// `labels`.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, PlayerLabels, board::RecursiveBoard};
+    ///
+    /// let o = Some(Player::Circle);
+    /// let board = RecursiveBoard::from_owners([o, None, None, None, None, None, None, None, None]);
+    ///
+    /// assert_eq!(
+    ///     board.describe_with_labels(&PlayerLabels::new("Ana", "Bruno")),
+    ///     "Bruno has won the top-left board."
+    /// );
+    /// ```
+    pub fn describe_with_labels(&self, labels: &PlayerLabels) -> String {
+        let mut sentences = Vec::new();
+
+        for player in [Player::Cross, Player::Circle] {
+            let won: Vec<_> = (0..9)
+                .filter(|&outer| self.cells[outer].owner() == Some(&player))
+                .map(|outer| AREA_NAMES[outer])
+                .collect();
+            if !won.is_empty() {
+                let boards = if won.len() == 1 { "board" } else { "boards" };
+                sentences.push(format!(
+                    "{} has won the {} {boards}.",
+                    labels.name(player),
+                    join_names(&won)
+                ));
+            }
+        }
+
+        let drawn: Vec<_> = (0..9)
+            .filter(|&outer| self.cells[outer].state == BoardState::Over(BoardResult::Draw))
+            .map(|outer| AREA_NAMES[outer])
+            .collect();
+        match drawn.len() {
+            0 => {}
+            1 => sentences.push(format!("The {} board is drawn.", drawn[0])),
+            _ => sentences.push(format!("The {} boards are drawn.", join_names(&drawn))),
+        }
+
+        match self.get_state() {
+            BoardState::Over(BoardResult::Winner(player)) => {
+                sentences.push(format!("{} has won the game.", labels.name(player)));
+            }
+            BoardState::Over(BoardResult::Draw) => {
+                sentences.push(String::from("The game is a draw."))
+            }
+            BoardState::InProgress => {
+                if sentences.is_empty() {
+                    sentences.push(String::from("No board has been decided yet."));
+                }
+                sentences.extend(
+                    self.describe_threats(labels)
+                        .into_iter()
+                        .map(|(_, sentence)| sentence),
+                );
+            }
+        }
+
+        sentences.join(" ")
+    }
+
+    #[must_use]
+    /// Describes `mv` in plain English, like [`RecursiveBoard::describe`]. The move is expected to
+    /// have just been played on this board, so the description includes what it achieved: winning
+    /// its sub-board or the game, and the outer lines its player now threatens.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{CellPosition, RecursiveBoard}};
+    ///
+    /// let mut board = RecursiveBoard::new();
+    /// let mv = CellPosition::new(4, 2).by(Player::Cross);
+    /// board.try_play(mv).unwrap();
+    ///
+    /// assert_eq!(board.describe_move(mv), "X plays in the center board, top-right cell.");
+    /// ```
+    pub fn describe_move(&self, mv: Move) -> String {
+        self.describe_move_with_labels(mv, &PlayerLabels::default())
+    }
+
+    #[must_use]
+    /// Same as [`RecursiveBoard::describe_move`], but the [`Player`]s are called by their names in
+    /// `labels`.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, PlayerLabels, board::{CellPosition, RecursiveBoard}};
+    ///
+    /// let mut board = RecursiveBoard::new();
+    /// let mv = CellPosition::new(0, 8).by(Player::Circle);
+    /// board.try_play(mv).unwrap();
+    ///
+    /// assert_eq!(
+    ///     board.describe_move_with_labels(mv, &PlayerLabels::new("Ana", "Bruno")),
+    ///     "Bruno plays in the top-left board, bottom-right cell."
+    /// );
+    /// ```
+    pub fn describe_move_with_labels(&self, mv: Move, labels: &PlayerLabels) -> String {
+        let player = labels.name(mv.player);
+        let board = AREA_NAMES[mv.position.outer().get()];
+        let mut sentences = vec![format!(
+            "{player} plays in the {board} board, {} cell.",
+            AREA_NAMES[mv.position.inner().get()]
+        )];
+
+        if self.sub_board_at(mv.position.outer()).owner() == Some(&mv.player) {
+            sentences.push(format!("{player} wins the {board} board."));
+            if self.get_state() == BoardState::Over(BoardResult::Winner(mv.player)) {
+                sentences.push(format!("{player} wins the game."));
+            } else {
+                sentences.extend(
+                    self.describe_threats(labels)
+                        .into_iter()
+                        .filter(|(threatening, _)| *threatening == mv.player)
+                        .map(|(_, sentence)| sentence),
+                );
+            }
+        }
+
+        sentences.join(" ")
+    }
+
+    /// One sentence for each player that is one sub-board away from completing outer lines, along
+    /// with that player.
+    fn describe_threats(&self, labels: &PlayerLabels) -> Vec<(Player, String)> {
+        let mut sentences = Vec::new();
+        for player in [Player::Cross, Player::Circle] {
+            let threatened: Vec<_> = WIN_LINES
+                .iter()
+                .zip(LINE_NAMES)
+                .filter(|(line, _)| {
+                    let owned = line
+                        .iter()
+                        .filter(|&&outer| self.cells[outer].owner() == Some(&player))
+                        .count();
+                    let open = line
+                        .iter()
+                        .filter(|&&outer| self.cells[outer].state == BoardState::InProgress)
+                        .count();
+                    owned == 2 && open == 1
+                })
+                .map(|(_, name)| name)
+                .collect();
+            if !threatened.is_empty() {
+                sentences.push((
+                    player,
+                    format!(
+                        "{} threatens the {} of the main board.",
+                        labels.name(player),
+                        join_names(&threatened)
+                    ),
+                ));
+            }
+        }
+        sentences
+    }
+
+    #[must_use]
+    /// Checks whether the position could have come up in a game played by the rules.
+    ///
+    /// This can't fully validate a position without its move history, so it only rejects the ones
+    /// that are provably impossible:
+    /// - [`Player::Cross`] moves first and the players alternate turns, so Cross must have placed
+    ///   as many marks as [`Player::Circle`], or one more.
+    /// - No sub-board, nor the outer board, may have lines completed by both players, since a
+    ///   board stops being played as soon as one of them wins it.
+    /// - For the same reason, all the lines a player completed on a board must share a cell: the
+    ///   one their last move there was in.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::str::FromStr;
+    /// use tic_tac_toe::board::{InnerBoard, RecursiveBoard};
+    ///
+    /// let mut boards = [const { InnerBoard::new() }; 9];
+    /// boards[4] = InnerBoard::from_str("X---O----").unwrap();
+    /// assert!(RecursiveBoard::from(boards.clone()).is_reachable());
+    ///
+    /// // Cross can't have played twice in a row.
+    /// boards[4] = InnerBoard::from_str("X-X------").unwrap();
+    /// assert!(!RecursiveBoard::from(boards).is_reachable());
+    /// ```
+    pub fn is_reachable(&self) -> bool {
+        let (mut circles, mut crosses) = (0usize, 0usize);
+        for cell in &self.cells {
+            for inner_cell in 0..9 {
+                match cell.board().get_cell(inner_cell) {
+                    Some(Player::Circle) => circles += 1,
+                    Some(Player::Cross) => crosses += 1,
+                    None => {}
+                }
+            }
+        }
+        if !matches!(crosses.checked_sub(circles), Some(0 | 1)) {
+            return false;
+        }
+
+        let owners = self.cells.each_ref().map(|cell| cell.owner().copied());
+        std::iter::once(InnerBoard::from(owners))
+            .chain(self.cells.iter().map(|cell| cell.board().clone()))
+            .all(|board| {
+                match (
+                    shared_line_cells(&board, Player::Cross),
+                    shared_line_cells(&board, Player::Circle),
+                ) {
+                    (Some(_), Some(_)) => false,
+                    (Some(shared), None) | (None, Some(shared)) => shared != 0,
+                    (None, None) => true,
+                }
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A compact key for a [`RecursiveBoard`], meant for solver and memoization tables. Returned by
+/// [`RecursiveBoard::compressed_key`].
+///
+/// Each sub-board takes 2 bits for its outcome, plus 15 more for its cells while it is still in
+/// progress, so the whole board always fits in 24 bytes.
+pub struct CompressedKey([u64; 3]);
+
+impl CompressedKey {
+    /// The bits needed for the cells of a sub-board in progress: `3^9` layouts fit in 15 bits.
+    const IN_PROGRESS_BITS: u32 = 15;
+
+    /// Writes the lowest `bits` bits of `value` at `offset`, moving it past them.
+    fn push(&mut self, offset: &mut u32, value: u64, bits: u32) {
+        for bit in 0..bits {
+            if value & (1 << bit) != 0 {
+                let position = *offset + bit;
+                self.0[(position / 64) as usize] |= 1 << (position % 64);
+            }
+        }
+        *offset += bits;
+    }
+}
+
+/// How each cell of a 3x3 board is called in descriptions.
+const AREA_NAMES: [&str; 9] = [
+    "top-left",
+    "top",
+    "top-right",
+    "left",
+    "center",
+    "right",
+    "bottom-left",
+    "bottom",
+    "bottom-right",
+];
+
+/// How each of the [`WIN_LINES`] is called in descriptions, in the same order.
+const LINE_NAMES: [&str; 8] = [
+    "top row",
+    "middle row",
+    "bottom row",
+    "left column",
+    "middle column",
+    "right column",
+    "main diagonal",
+    "anti-diagonal",
+];
+
+/// Joins `names` into an English list: "a", "a and b", "a, b and c".
+fn join_names(names: &[&str]) -> String {
+    match names {
+        [] => String::new(),
+        [name] => (*name).to_string(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// How a game is settled when the outer board ends without either player completing a line.
+/// Used by [`RecursiveBoard::get_state_with_tie_break`].
+pub enum TieBreak {
+    /// The game is a draw. Drawn sub-boards count for neither player. This is the standard rule.
+    #[default]
+    Draw,
+    /// The player who won the most sub-boards wins. It's still a draw if both won as many.
+    MostBoards,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A sub-board that would complete a line on the outer board if won. Returned by
+/// [`RecursiveBoard::outer_threats`].
+pub struct OuterThreat {
+    /// The index of the sub-board in the outer board.
+    pub outer: usize,
+    /// The cells of the sub-board that would win it with a single move. Empty if the sub-board
+    /// needs more than one move to be won.
+    pub winning_cells: Vec<usize>,
+}
+
+/// The cells that all the lines `player` has completed on `board` go through, as a mask with a bit
+/// per cell, or [`None`] if they haven't completed any line.
+fn shared_line_cells(board: &InnerBoard, player: Player) -> Option<u16> {
+    WIN_LINES
+        .iter()
+        .filter(|line| {
+            line.iter()
+                .all(|&cell| board.get_cell(cell) == &Some(player))
+        })
+        .map(|line| line.iter().fold(0, |mask, &cell| mask | 1 << cell))
+        .reduce(|shared, mask| shared & mask)
+}
+
+#[derive(Clone, Default)]
+/// Builds a [`RecursiveBoard`] sub-board by sub-board. Returned by [`RecursiveBoard::builder`].
+///
+/// Like [`RecursiveBoard::set_cell_unchecked`], this doesn't check the rules, so it can set up
+/// positions that can't be reached through actual play.
+pub struct RecursiveBoardBuilder {
+    board: RecursiveBoard,
+}
+
+impl RecursiveBoardBuilder {
+    #[must_use]
+    /// Replaces the `outer` sub-board with `board`, like [`RecursiveBoard::fill_sub_board`].
+    pub fn sub_board(mut self, outer: usize, board: InnerBoard) -> Self {
+        self.board.fill_sub_board(outer, board);
+        self
+    }
+
+    #[must_use]
+    /// Makes the `outer` sub-board won by `player`, filled like in [`RecursiveBoard::from_owners`].
+    pub fn owner(mut self, outer: usize, player: Player) -> Self {
+        check_index!("RecursiveBoard", outer);
+        self.board.cells[outer].set_owner(Some(player));
+        self
+    }
+
+    #[must_use]
+    /// Places `player`'s mark in the `inner` cell of the `outer` sub-board.
+    pub fn mark(mut self, outer: usize, inner: usize, player: Player) -> Self {
+        self.board.set_cell_unchecked(outer, inner, Some(player));
+        self
+    }
+
+    #[must_use]
+    /// Returns the board built so far.
+    pub fn build(self) -> RecursiveBoard {
+        self.board
+    }
 }
 
 impl Board<RecursiveCell> for RecursiveBoard {
     fn get_cell(&self, cell: usize) -> &RecursiveCell {
+        check_index!("RecursiveBoard", cell);
         &self.cells[cell]
     }
 }
 
+impl super::FromCells<RecursiveCell> for RecursiveBoard {
+    fn from_cells(cells: [RecursiveCell; 9]) -> Self {
+        Self { cells }
+    }
+}
+
 impl From<[InnerBoard; 9]> for RecursiveBoard {
     fn from(value: [InnerBoard; 9]) -> Self {
         Self {
@@ -41,12 +908,131 @@ impl Default for RecursiveBoard {
     }
 }
 
+/// Generates positions reached through actual play: [`Player::Cross`] starts, each move is sent
+/// to the sub-board matching the previous move's cell unless that one is over, and the game stops
+/// once the outer board is decided or the data runs out.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RecursiveBoard {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut board = Self::new();
+        let mut player = Player::Cross;
+        let mut forced: Option<OuterIdx> = None;
+
+        for _ in 0..u.int_in_range(0..=81)? {
+            if board.get_state() != BoardState::InProgress {
+                break;
+            }
+            let mut mask = board.playable_mask();
+            if let Some(outer) = forced
+                && board.sub_board_at(outer).state == BoardState::InProgress
+            {
+                mask &= 0x1ff << (outer.get() * 9);
+            }
+            if mask == 0 {
+                break;
+            }
+
+            for _ in 0..u.choose_index(mask.count_ones() as usize)? {
+                mask &= mask - 1;
+            }
+            let position = CellPosition::from_index(mask.trailing_zeros() as usize);
+            board
+                .try_play(position.by(player))
+                .expect("playable cells accept marks");
+
+            forced = Some(position.inner().sends_to());
+            player = player.opponent();
+        }
+        Ok(board)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, None)
+    }
+}
+
 impl Display for RecursiveBoard {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         <Self as BoardDisplay<_>>::fmt(self, f)
     }
 }
 
+/// Prints each sub-board in the same format as [`InnerBoard`]'s [`FromStr`](std::str::FromStr),
+/// separated by `/`, in a single line.
+///
+/// The alternate flag (`{:#?}`) prints the full 9x9 grid instead, in a diagram that can be read
+/// back with [`RecursiveBoard::from_diagram`].
+///
+/// # Examples
+/// ```
+/// use tic_tac_toe::{Player, board::RecursiveBoard};
+///
+/// let mut board = RecursiveBoard::new();
+/// board.try_set_cell(4, 4, Player::Cross).unwrap();
+/// board.try_set_cell(4, 0, Player::Circle).unwrap();
+///
+/// assert_eq!(
+///     format!("{board:?}"),
+///     "RecursiveBoard(---------/---------/---------/---------/O---X----/---------/---------/---------/---------)"
+/// );
+/// assert_eq!(
+///     format!("{board:#?}"),
+///     "\
+/// RecursiveBoard {
+///     . . . | . . . | . . .
+///     . . . | . . . | . . .
+///     . . . | . . . | . . .
+///     ------+-------+------
+///     . . . | O . . | . . .
+///     . . . | . X . | . . .
+///     . . . | . . . | . . .
+///     ------+-------+------
+///     . . . | . . . | . . .
+///     . . . | . . . | . . .
+///     . . . | . . . | . . .
+/// }"
+/// );
+/// ```
+impl Debug for RecursiveBoard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mark = |value: &Option<Player>, empty| value.as_ref().map_or(empty, char::from);
+
+        if !f.alternate() {
+            write!(f, "RecursiveBoard(")?;
+            for (outer, cell) in self.cells.iter().enumerate() {
+                if outer != 0 {
+                    write!(f, "/")?;
+                }
+                for inner in 0..9 {
+                    write!(f, "{}", mark(cell.board().get_cell(inner), '-'))?;
+                }
+            }
+            return write!(f, ")");
+        }
+
+        writeln!(f, "RecursiveBoard {{")?;
+        for row in 0..9 {
+            if row != 0 && row % 3 == 0 {
+                writeln!(f, "    ------+-------+------")?;
+            }
+            write!(f, "   ")?;
+            for column in 0..9 {
+                if column != 0 && column % 3 == 0 {
+                    write!(f, " |")?;
+                }
+                let position = CellPosition::from_grid(row, column);
+                let value = self
+                    .sub_board_at(position.outer())
+                    .board()
+                    .cell(position.inner());
+                write!(f, " {}", mark(value, '.'))?;
+            }
+            writeln!(f)?;
+        }
+        write!(f, "}}")
+    }
+}
+
 /// Concerns the [`RecursiveCell`] type, which is in each cell of the [`RecursiveBoard`].
 pub mod cell {
     use super::*;
@@ -59,9 +1045,96 @@ pub mod cell {
     /// The former contains the individual game itself, represented by an [`InnerBoard`],
     /// whilst the latter is basically a cache for the [`BoardState`] returned by the `board`'s
     /// [`Board::get_state`], so it doesn't need to be updated all the time.
+    ///
+    /// A [`SubBoardSummary`] of the board is cached alongside them for the same reason.
     pub struct RecursiveCell {
         board: InnerBoard,
         pub(super) state: BoardState,
+        summary: SubBoardSummary,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    /// An overview of a [`RecursiveCell`]'s board, enough to show its progress without looking
+    /// at each of its cells. Returned by [`RecursiveCell::summary`].
+    pub struct SubBoardSummary {
+        /// The [`Player`] who won the board, if any.
+        pub owner: Option<Player>,
+        /// How many cells of the board are still empty.
+        pub empty_cells: u8,
+        /// How many cells belong to [`Player::Cross`].
+        pub x_count: u8,
+        /// How many cells belong to [`Player::Circle`].
+        pub o_count: u8,
+        /// How many empty cells would immediately win the board for [`Player::Cross`] and
+        /// [`Player::Circle`], in that order. Always zero for boards that are over.
+        pub immediate_threats: (u8, u8),
+    }
+
+    impl SubBoardSummary {
+        const EMPTY: Self = Self {
+            owner: None,
+            empty_cells: 9,
+            x_count: 0,
+            o_count: 0,
+            immediate_threats: (0, 0),
+        };
+
+        fn of(board: &InnerBoard, state: &BoardState) -> Self {
+            let mut summary = Self {
+                owner: state_owner(state).copied(),
+                ..Self::EMPTY
+            };
+            for cell in 0..9 {
+                match board.get_cell(cell) {
+                    Some(Player::Cross) => summary.x_count += 1,
+                    Some(Player::Circle) => summary.o_count += 1,
+                    None => continue,
+                }
+                summary.empty_cells -= 1;
+            }
+            summary.immediate_threats = Self::threats(board, state);
+            summary
+        }
+
+        /// Accounts for a cell of `board` changing from `previous` to `value`. The mark counts are
+        /// adjusted rather than recounted; the threats depend on whole lines, so those are redone.
+        fn update(
+            &mut self,
+            board: &InnerBoard,
+            state: &BoardState,
+            previous: Option<Player>,
+            value: Option<Player>,
+        ) {
+            for (mark, delta) in [(previous, -1i8), (value, 1)] {
+                let count = match mark {
+                    Some(Player::Cross) => &mut self.x_count,
+                    Some(Player::Circle) => &mut self.o_count,
+                    None => continue,
+                };
+                *count = count.wrapping_add_signed(delta);
+                self.empty_cells = self.empty_cells.wrapping_add_signed(-delta);
+            }
+            self.owner = state_owner(state).copied();
+            self.immediate_threats = Self::threats(board, state);
+        }
+
+        fn threats(board: &InnerBoard, state: &BoardState) -> (u8, u8) {
+            if *state != BoardState::InProgress {
+                return (0, 0);
+            }
+            let threats = |player| board.winning_cells(player).count() as u8;
+            (threats(Player::Cross), threats(Player::Circle))
+        }
+    }
+
+    fn state_owner(state: &BoardState) -> Option<&Player> {
+        match state {
+            BoardState::InProgress => None,
+            BoardState::Over(result) => match result {
+                BoardResult::Draw => None,
+                BoardResult::Winner(player) => Some(player),
+            },
+        }
     }
 
     impl RecursiveCell {
@@ -71,19 +1144,88 @@ pub mod cell {
             Self {
                 board: InnerBoard::new(),
                 state: BoardState::InProgress,
+                summary: SubBoardSummary::EMPTY,
+            }
+        }
+
+        /// Returns a [`RecursiveCell`] won by `player`, with their marks on the top row.
+        pub(super) const fn won_by(player: Player) -> Self {
+            let mark = Some(player);
+            let (x_count, o_count) = match player {
+                Player::Cross => (3, 0),
+                Player::Circle => (0, 3),
+            };
+            Self {
+                board: InnerBoard::from_cells([
+                    mark, mark, mark, None, None, None, None, None, None,
+                ]),
+                state: BoardState::Over(BoardResult::Winner(player)),
+                summary: SubBoardSummary {
+                    owner: mark,
+                    empty_cells: 6,
+                    x_count,
+                    o_count,
+                    immediate_threats: (0, 0),
+                },
+            }
+        }
+
+        #[must_use]
+        /// Returns the [`InnerBoard`] that is played in this cell.
+        pub const fn board(&self) -> &InnerBoard {
+            &self.board
+        }
+
+        /// Sets a cell of the board, updating the cached state and summary.
+        pub(super) fn set_cell(&mut self, cell: usize, value: Option<Player>) {
+            let previous = *self.board.get_cell(cell);
+            self.board.set_cell(cell, value);
+            self.state = self.board.get_state();
+            self.summary
+                .update(&self.board, &self.state, previous, value);
+        }
+
+        #[must_use]
+        /// Returns the cached [`SubBoardSummary`] of this cell's board.
+        ///
+        /// # Examples
+        /// ```
+        /// # use std::str::FromStr;
+        /// use tic_tac_toe::board::{InnerBoard, recursive::RecursiveCell};
+        ///
+        /// let cell = RecursiveCell::from(InnerBoard::from_str("XX-OO----").unwrap());
+        /// let summary = cell.summary();
+        ///
+        /// assert_eq!(summary.owner, None);
+        /// assert_eq!(summary.empty_cells, 5);
+        /// assert_eq!((summary.x_count, summary.o_count), (2, 2));
+        /// assert_eq!(summary.immediate_threats, (1, 1));
+        /// ```
+        pub const fn summary(&self) -> SubBoardSummary {
+            self.summary
+        }
+
+        #[must_use]
+        /// Returns the result of this cell's board with perfect play, `next_player` being the one to
+        /// move in it. See [`InnerBoard::solve`].
+        ///
+        /// Useful to tell apart boards that are still in progress but are already decided, such as
+        /// a board that is drawn with best play.
+        pub fn solve(&self, next_player: crate::Player) -> BoardResult {
+            match &self.state {
+                BoardState::Over(result) => result.clone(),
+                BoardState::InProgress => self.board.solve(next_player),
             }
         }
     }
 
     impl Cell for RecursiveCell {
         fn owner(&self) -> Option<&crate::Player> {
-            match &self.state {
-                BoardState::InProgress => None,
-                BoardState::Over(result) => match result {
-                    BoardResult::Draw => None,
-                    BoardResult::Winner(player) => Some(player),
-                },
-            }
+            state_owner(&self.state)
+        }
+
+        fn is_decided(&self) -> bool {
+            self.state != BoardState::InProgress
         }
 
         fn as_char(&self) -> char {
@@ -91,10 +1233,23 @@ pub mod cell {
         }
     }
 
+    impl CellMut for RecursiveCell {
+        /// Replaces the board with one won by `owner`, filled like in
+        /// [`RecursiveBoard::from_owners`], or with an empty one for [`None`].
+        fn set_owner(&mut self, owner: Option<Player>) {
+            *self = match owner {
+                Some(player) => Self::won_by(player),
+                None => Self::new(),
+            };
+        }
+    }
+
     impl From<InnerBoard> for RecursiveCell {
         fn from(value: InnerBoard) -> Self {
+            let state = value.get_state();
             Self {
-                state: value.get_state(),
+                summary: SubBoardSummary::of(&value, &state),
+                state,
                 board: value,
             }
         }
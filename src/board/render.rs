@@ -0,0 +1,383 @@
+use super::{CellPosition, RecursiveBoard, cell::Cell};
+use crate::{Player, PlayerLabels};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// Options for [`RecursiveBoard::render`].
+pub struct RenderOptions {
+    forced: Option<usize>,
+    orientation: Orientation,
+    overlay_won: bool,
+    labels: PlayerLabels,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// Which way the grid is drawn by [`RecursiveBoard::render`]. The coordinate labels move with the
+/// cells, so they still name the same [`CellPosition`]s.
+pub enum Orientation {
+    #[default]
+    /// `a9` at the top-left.
+    Normal,
+    /// Turned half a turn, with `i1` at the top-left: the board as seen from across the table.
+    Rotated,
+    /// Flipped left to right, with `i9` at the top-left.
+    MirroredHorizontally,
+    /// Flipped top to bottom, with `a1` at the top-left.
+    MirroredVertically,
+}
+
+impl Orientation {
+    /// Returns the cell drawn at `row` and `column` of the rendered grid, counting from the
+    /// top-left.
+    const fn position_at(self, row: usize, column: usize) -> CellPosition {
+        let (row, column) = match self {
+            Self::Normal => (row, column),
+            Self::Rotated => (8 - row, 8 - column),
+            Self::MirroredHorizontally => (row, 8 - column),
+            Self::MirroredVertically => (8 - row, column),
+        };
+        CellPosition::from_grid(row, column)
+    }
+}
+
+impl RenderOptions {
+    #[must_use]
+    /// Marks the `outer` sub-board as the one the next move must be played in. Its empty cells
+    /// are drawn as `*` instead of `.`.
+    pub const fn forced(mut self, outer: usize) -> Self {
+        debug_assert!(outer < 9);
+        self.forced = Some(outer);
+        self
+    }
+
+    #[must_use]
+    /// Draws the grid in the given [`Orientation`].
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{RecursiveBoard, render::{Orientation, RenderOptions}}};
+    ///
+    /// let mut board = RecursiveBoard::new();
+    /// board.try_set_cell(0, 0, Player::Cross).unwrap();
+    ///
+    /// let options = RenderOptions::default().orientation(Orientation::Rotated);
+    /// let rendered = board.render(&options);
+    /// let lines: Vec<&str> = rendered.lines().collect();
+    ///
+    /// assert_eq!(lines[0], "    i h g   f e d   c b a");
+    /// assert_eq!(lines[2], "1 | . . . | . . . | . . . | 1");
+    /// assert_eq!(lines[12], "9 | . . . | . . . | . . X | 9");
+    /// ```
+    pub const fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    #[must_use]
+    /// Draws each won sub-board as a single large mark of its winner instead of its cells, so the
+    /// outer board can be read at a glance.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::str::FromStr;
+    /// use tic_tac_toe::{Player, board::{InnerBoard, RecursiveBoard, render::RenderOptions}};
+    ///
+    /// let mut board = RecursiveBoard::new();
+    /// board.fill_sub_board(0, InnerBoard::from_str("XXXOO----").unwrap());
+    /// board.fill_sub_board(1, InnerBoard::from_str("OOOXX-X--").unwrap());
+    ///
+    /// let rendered = board.render(&RenderOptions::default().overlay_won());
+    /// let lines: Vec<&str> = rendered.lines().collect();
+    ///
+    /// assert_eq!(lines[2], "9 | X . X | O O O | . . . | 9");
+    /// assert_eq!(lines[3], "8 | . X . | O . O | . . . | 8");
+    /// assert_eq!(lines[4], "7 | X . X | O O O | . . . | 7");
+    /// ```
+    pub const fn overlay_won(mut self) -> Self {
+        self.overlay_won = true;
+        self
+    }
+
+    #[must_use]
+    /// Draws each player's marks with the ones in `labels`, both in the cells and in the
+    /// [overlay](RenderOptions::overlay_won) of won sub-boards.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, PlayerLabels, board::{RecursiveBoard, render::RenderOptions}};
+    ///
+    /// let mut board = RecursiveBoard::new();
+    /// board.try_set_cell(0, 0, Player::Cross).unwrap();
+    /// board.try_set_cell(0, 1, Player::Circle).unwrap();
+    ///
+    /// let labels = PlayerLabels::default().with_marks('A', 'B');
+    /// let rendered = board.render(&RenderOptions::default().labels(labels));
+    /// assert_eq!(rendered.lines().nth(2), Some("9 | A B . | . . . | . . . | 9"));
+    /// ```
+    pub fn labels(mut self, labels: PlayerLabels) -> Self {
+        self.labels = labels;
+        self
+    }
+}
+
+impl RecursiveBoard {
+    #[must_use]
+    /// Renders the full 9x9 grid as plain ASCII, with the algebraic coordinates of
+    /// [`CellPosition`] on all four margins. The output has no characters that Markdown code
+    /// blocks treat specially, so chat bots can post it as-is.
+    ///
+    /// # Examples
+    /// ```
+    /// use tic_tac_toe::{Player, board::{RecursiveBoard, render::RenderOptions}};
+    ///
+    /// let mut board = RecursiveBoard::new();
+    /// board.try_set_cell(4, 4, Player::Cross).unwrap();
+    ///
+    /// assert_eq!(
+    ///     board.render(&RenderOptions::default().forced(4)),
+    ///     "    a b c   d e f   g h i
+    ///   +-------+-------+-------+
+    /// 9 | . . . | . . . | . . . | 9
+    /// 8 | . . . | . . . | . . . | 8
+    /// 7 | . . . | . . . | . . . | 7
+    ///   +-------+-------+-------+
+    /// 6 | . . . | * * * | . . . | 6
+    /// 5 | . . . | * X * | . . . | 5
+    /// 4 | . . . | * * * | . . . | 4
+    ///   +-------+-------+-------+
+    /// 3 | . . . | . . . | . . . | 3
+    /// 2 | . . . | . . . | . . . | 2
+    /// 1 | . . . | . . . | . . . | 1
+    ///   +-------+-------+-------+
+    ///     a b c   d e f   g h i
+    /// "
+    /// );
+    /// ```
+    pub fn render(&self, options: &RenderOptions) -> String {
+        const BORDER: &str = "  +-------+-------+-------+\n";
+
+        let orientation = options.orientation;
+        let mut columns = String::from("   ");
+        for column in 0..9 {
+            if column % 3 == 0 && column != 0 {
+                columns.push_str("  ");
+            }
+            let label = orientation.position_at(0, column).column() as u8;
+            columns.push(' ');
+            columns.push(char::from(b'a' + label));
+        }
+        columns.push('\n');
+
+        let mut result = columns.clone();
+        for row in 0..9 {
+            if row % 3 == 0 {
+                result.push_str(BORDER);
+            }
+            let label = 9 - orientation.position_at(row, 0).row();
+            result.push_str(&format!("{label} |"));
+            for column in 0..9 {
+                let position = orientation.position_at(row, column);
+                result.push(' ');
+                result.push(self.render_cell(position, options));
+                if column % 3 == 2 {
+                    result.push_str(" |");
+                }
+            }
+            result.push_str(&format!(" {label}\n"));
+        }
+        result.push_str(BORDER);
+        result.push_str(&columns);
+        result
+    }
+
+    /// The character a single cell is rendered as.
+    fn render_cell(&self, position: CellPosition, options: &RenderOptions) -> char {
+        let sub_board = self.sub_board_at(position.outer());
+        if options.overlay_won
+            && let Some(&winner) = sub_board.owner()
+        {
+            return match (winner, position.inner().get()) {
+                (Player::Cross, 0 | 2 | 4 | 6 | 8) | (Player::Circle, 0..=3 | 5..=8) => {
+                    options.labels.mark(winner)
+                }
+                _ => '.',
+            };
+        }
+
+        match sub_board.board().cell(position.inner()) {
+            Some(player) => options.labels.mark(*player),
+            None if options.forced == Some(position.outer().get()) => '*',
+            None => '.',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// Redraws a [`RecursiveBoard::render`] in a terminal by only rewriting the cells that changed,
+/// instead of printing the whole grid again after each move.
+///
+/// The first call to [`IncrementalRenderer::update`] returns the full rendering. Following calls
+/// return ANSI escape sequences that overwrite the changed cells of the grid printed before, and
+/// put the cursor back where it was. They expect the cursor to still be on the line right after
+/// the grid, which is where printing the full rendering leaves it. Only the cells are redrawn, so a
+/// new renderer is needed to change the [`Orientation`].
+///
+/// # Examples
+/// ```
+/// use tic_tac_toe::{Player, board::{RecursiveBoard, render::{IncrementalRenderer, RenderOptions}}};
+///
+/// let mut board = RecursiveBoard::new();
+/// let mut renderer = IncrementalRenderer::new();
+/// let options = RenderOptions::default();
+///
+/// assert_eq!(renderer.update(&board, &options), board.render(&options));
+///
+/// board.try_set_cell(0, 0, Player::Cross).unwrap();
+/// // Save the cursor, go 13 lines up to the top row, to the column of `a`, and write the mark.
+/// assert_eq!(renderer.update(&board, &options), "\x1b7\x1b[13A\x1b[5GX\x1b8");
+///
+/// assert_eq!(renderer.update(&board, &options), "");
+/// ```
+pub struct IncrementalRenderer {
+    previous: Option<[char; 81]>,
+}
+
+impl IncrementalRenderer {
+    /// How many lines [`RecursiveBoard::render`] outputs.
+    const LINES: usize = 15;
+
+    #[must_use]
+    /// Returns a renderer that hasn't drawn anything yet.
+    pub const fn new() -> Self {
+        Self { previous: None }
+    }
+
+    /// Returns what needs to be printed for the terminal to show `board` rendered with `options`.
+    pub fn update(&mut self, board: &RecursiveBoard, options: &RenderOptions) -> String {
+        let mut current = [' '; 81];
+        for (index, cell) in current.iter_mut().enumerate() {
+            let position = options.orientation.position_at(index / 9, index % 9);
+            *cell = board.render_cell(position, options);
+        }
+
+        let Some(previous) = self.previous.replace(current) else {
+            return board.render(options);
+        };
+
+        let mut result = String::new();
+        for (index, (&old, &new)) in previous.iter().zip(&current).enumerate() {
+            if old == new {
+                continue;
+            }
+            let (row, column) = (index / 9, index % 9);
+            let line = 2 + row + row / 3;
+            let screen_column = 5 + 2 * column + 2 * (column / 3);
+            result.push_str(&format!(
+                "\x1b7\x1b[{}A\x1b[{screen_column}G{new}\x1b8",
+                Self::LINES - line
+            ));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::InnerBoard;
+
+    #[test]
+    fn incremental_updates_point_at_the_rendered_cells() {
+        let mut board = RecursiveBoard::new();
+        let options = RenderOptions::default();
+        let mut renderer = IncrementalRenderer::new();
+        let _ = renderer.update(&board, &options);
+
+        for index in 0..81 {
+            let position = CellPosition::from_grid(index / 9, index % 9);
+            board.set_cell_unchecked(
+                position.outer().get(),
+                position.inner().get(),
+                Some(Player::Circle),
+            );
+            let update = renderer.update(&board, &options);
+            let rendered = board.render(&options);
+            board.set_cell_unchecked(position.outer().get(), position.inner().get(), None);
+            let _ = renderer.update(&board, &options);
+
+            // Follow the escape sequence into the full rendering.
+            let numbers: Vec<usize> = update
+                .split(|c: char| !c.is_ascii_digit())
+                .filter_map(|number| number.parse().ok())
+                .collect();
+            let [_, up, column, _] = numbers[..] else {
+                panic!("Unexpected update {update:?}");
+            };
+            let line = rendered
+                .lines()
+                .nth(IncrementalRenderer::LINES - up)
+                .unwrap();
+            assert_eq!(
+                line.chars().nth(column - 1),
+                Some('O'),
+                "Points at the wrong place for {position}"
+            );
+            assert!(
+                update.contains("GO"),
+                "Doesn't write the mark for {position}"
+            );
+        }
+    }
+
+    #[test]
+    fn orientations_keep_labels_on_their_cells() {
+        for orientation in [
+            Orientation::Normal,
+            Orientation::Rotated,
+            Orientation::MirroredHorizontally,
+            Orientation::MirroredVertically,
+        ] {
+            let options = RenderOptions::default().orientation(orientation);
+            for index in 0..81 {
+                let position = CellPosition::from_index(index);
+                let mut board = RecursiveBoard::new();
+                board.set_cell_unchecked(
+                    position.outer().get(),
+                    position.inner().get(),
+                    Some(Player::Circle),
+                );
+                let rendered = board.render(&options);
+                let lines: Vec<&str> = rendered.lines().collect();
+
+                let line = lines.iter().find(|line| line.contains('O')).unwrap();
+                let column = line.find('O').unwrap();
+                let column_label = char::from(lines[0].as_bytes()[column]);
+                let row_label = line.chars().next().unwrap();
+                assert_eq!(
+                    format!("{column_label}{row_label}"),
+                    position.to_algebraic(),
+                    "{orientation:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn labels_apply_to_cells_and_overlays() {
+        let mut board = RecursiveBoard::new();
+        board.fill_sub_board(0, InnerBoard::builder().x(0).x(1).x(2).build());
+        board.try_set_cell(4, 4, Player::Circle).unwrap();
+        let options = RenderOptions::default()
+            .overlay_won()
+            .labels(PlayerLabels::default().with_marks('A', 'B'));
+
+        let rendered = board.render(&options);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[2], "9 | A . A | . . . | . . . | 9");
+        assert_eq!(lines[7], "5 | . . . | . B . | . . . | 5");
+
+        let mut renderer = IncrementalRenderer::new();
+        let _ = renderer.update(&board, &options);
+        board.try_set_cell(8, 8, Player::Cross).unwrap();
+        assert!(renderer.update(&board, &options).contains("GA"));
+    }
+}
@@ -113,3 +113,467 @@ fn get_board_state() {
         "Doesn't recognize diagonal win"
     );
 }
+
+#[test]
+fn recursive_board_reachability() {
+    use std::str::FromStr;
+
+    assert!(
+        RecursiveBoard::new().is_reachable(),
+        "Doesn't accept the empty board."
+    );
+
+    let mut boards = [const { InnerBoard::new() }; 9];
+    boards[0] = InnerBoard::from_str("XXXOO----").unwrap();
+    boards[1] = InnerBoard::from_str("O--------").unwrap();
+    assert!(
+        RecursiveBoard::from(boards.clone()).is_reachable(),
+        "Doesn't accept a won sub-board."
+    );
+
+    boards[0] = InnerBoard::from_str("XXXOOO---").unwrap();
+    boards[1] = InnerBoard::new();
+    assert!(
+        !RecursiveBoard::from(boards).is_reachable(),
+        "Doesn't reject a sub-board won by both players."
+    );
+
+    let won = InnerBoard::from_str("XXX------").unwrap();
+    let lost = InnerBoard::from_str("OOO------").unwrap();
+    let boards = [
+        won.clone(),
+        won.clone(),
+        won,
+        lost.clone(),
+        lost.clone(),
+        lost,
+        InnerBoard::new(),
+        InnerBoard::new(),
+        InnerBoard::new(),
+    ];
+    assert!(
+        !RecursiveBoard::from(boards).is_reachable(),
+        "Doesn't reject an outer board won by both players."
+    );
+
+    let mut boards = [const { InnerBoard::new() }; 9];
+    boards[0] = InnerBoard::from_str("XXX---XXX").unwrap();
+    boards[1] = InnerBoard::from_str("OOOO-----").unwrap();
+    boards[2] = InnerBoard::from_str("O--------").unwrap();
+    assert!(
+        !RecursiveBoard::from(boards.clone()).is_reachable(),
+        "Doesn't reject two lines that no single move completes."
+    );
+
+    boards[0] = InnerBoard::from_str("XXXX--X--").unwrap();
+    assert!(
+        RecursiveBoard::from(boards.clone()).is_reachable(),
+        "Doesn't accept lines that share the last move."
+    );
+
+    let mut boards = [const { InnerBoard::new() }; 9];
+    boards[4] = InnerBoard::from_str("O--------").unwrap();
+    assert!(
+        !RecursiveBoard::from(boards).is_reachable(),
+        "Doesn't reject Circle moving first."
+    );
+}
+
+#[test]
+fn recursive_board_try_set_cell() {
+    use crate::errors::SetCellError;
+
+    let mut board = RecursiveBoard::new();
+    for inner in [0, 1, 2] {
+        assert_eq!(board.try_set_cell(0, inner, Player::Cross), Ok(()));
+    }
+    assert_eq!(board.get_cell(0).owner(), Some(&Player::Cross));
+    assert_eq!(
+        board.try_set_cell(0, 5, Player::Circle),
+        Err(SetCellError::BoardDecided { outer: 0 }),
+        "Allows writing to a decided board."
+    );
+
+    assert_eq!(board.try_set_cell(1, 4, Player::Circle), Ok(()));
+    assert_eq!(
+        board.try_set_cell(1, 4, Player::Cross),
+        Err(SetCellError::CellOccupied { outer: 1, inner: 4 }),
+        "Allows overwriting a mark."
+    );
+    assert_eq!(board.get_cell(1).board().get_cell(4), &Some(Player::Circle));
+
+    // The unchecked version still goes through, and keeps the cached state up to date.
+    board.set_cell_unchecked(0, 1, None);
+    assert_eq!(board.get_cell(0).owner(), None);
+    let summary = board.get_cell(0).summary();
+    assert_eq!(summary.empty_cells, 7);
+    assert_eq!(summary.x_count, 2);
+    assert_eq!(summary.immediate_threats, (1, 0));
+}
+
+#[test]
+fn recursive_board_outer_threats() {
+    use crate::board::recursive::OuterThreat;
+    use std::str::FromStr;
+
+    let won = InnerBoard::from_str("OOO------").unwrap();
+    let mut boards = [const { InnerBoard::new() }; 9];
+    boards[0] = won.clone();
+    boards[1] = won.clone();
+    boards[4] = won;
+    boards[2] = InnerBoard::from_str("OO-X-X---").unwrap();
+    boards[8] = InnerBoard::from_str("OXOXXOXOX").unwrap();
+    let board = RecursiveBoard::from(boards);
+
+    // The drawn board 8 blocks the diagonal, just like an opponent's board would.
+    assert_eq!(
+        board.outer_threats(Player::Circle),
+        vec![
+            OuterThreat {
+                outer: 2,
+                winning_cells: vec![2]
+            },
+            OuterThreat {
+                outer: 7,
+                winning_cells: vec![]
+            },
+        ]
+    );
+    assert_eq!(board.outer_threats(Player::Cross), vec![]);
+
+    // Board 2 is still in progress, but Circle has a mark on each of its lines.
+    let won = InnerBoard::from_str("XXX------").unwrap();
+    let mut boards = [const { InnerBoard::new() }; 9];
+    boards[0] = won.clone();
+    boards[1] = won;
+    boards[2] = InnerBoard::from_str("OXOXOX-O-").unwrap();
+    let board = RecursiveBoard::from(boards);
+    assert_eq!(board.get_cell(2).owner(), None);
+    assert_eq!(board.outer_threats(Player::Cross), vec![]);
+
+    // Drawn boards completing an earlier line don't hide the threat on a later one.
+    let drawn = InnerBoard::from_str("OXOXXOXOX").unwrap();
+    let mut boards = [const { InnerBoard::new() }; 9];
+    boards[..3].fill(drawn);
+    boards[3] = InnerBoard::from_str("XXX------").unwrap();
+    boards[4] = boards[3].clone();
+    let board = RecursiveBoard::from(boards);
+    assert_eq!(
+        board.outer_threats(Player::Cross),
+        vec![OuterThreat {
+            outer: 5,
+            winning_cells: vec![]
+        }]
+    );
+}
+
+#[test]
+fn recursive_board_from_diagram_errors() {
+    use crate::errors::DiagramError;
+
+    let row = "...|...|...\n";
+    assert_eq!(
+        RecursiveBoard::from_diagram(&row.repeat(8)).err(),
+        Some(DiagramError::InvalidRowCount { rows: 8 })
+    );
+    assert_eq!(
+        RecursiveBoard::from_diagram(&row.repeat(10)).err(),
+        Some(DiagramError::InvalidRowCount { rows: 10 })
+    );
+    assert_eq!(
+        RecursiveBoard::from_diagram(&format!("{}...|...|..\n", row.repeat(2))).err(),
+        Some(DiagramError::InvalidRowLength { line: 2, cells: 8 })
+    );
+    assert_eq!(
+        RecursiveBoard::from_diagram(&format!("{}...|.#.|...\n", row.repeat(4))).err(),
+        Some(DiagramError::InvalidChar {
+            line: 4,
+            column: 5,
+            found: '#'
+        })
+    );
+}
+
+#[test]
+fn recursive_board_from_owners() {
+    use std::str::FromStr;
+
+    let owners = [
+        Some(Player::Cross),
+        None,
+        Some(Player::Circle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+    let board = RecursiveBoard::from_owners(owners);
+
+    // Matches what the cells would be if built at runtime.
+    let mut boards = [const { InnerBoard::new() }; 9];
+    boards[0] = InnerBoard::from_str("XXX------").unwrap();
+    boards[2] = InnerBoard::from_str("OOO------").unwrap();
+    let expected = RecursiveBoard::from(boards);
+    for (cell, owner) in owners.iter().enumerate() {
+        assert_eq!(board.get_cell(cell).owner(), owner.as_ref());
+        assert_eq!(
+            board.get_cell(cell).board(),
+            expected.get_cell(cell).board()
+        );
+        assert_eq!(
+            board.get_cell(cell).summary(),
+            expected.get_cell(cell).summary()
+        );
+    }
+}
+
+#[test]
+fn builders_match_literal_boards() {
+    use crate::board::recursive::cell::RecursiveCell;
+
+    let (x, o) = (Some(Player::Cross), Some(Player::Circle));
+    let inner = InnerBoard::builder().x(0).o(4).x(8).o(2).build();
+    assert_eq!(
+        inner,
+        InnerBoard::from([x, None, o, None, o, None, None, None, x])
+    );
+
+    let board = RecursiveBoard::builder()
+        .owner(0, Player::Circle)
+        .sub_board(5, inner.clone())
+        .mark(7, 1, Player::Cross)
+        .build();
+    let expected = RecursiveBoard::from_owners([o, None, None, None, None, None, None, None, None]);
+    assert_eq!(board.get_cell(0).board(), expected.get_cell(0).board());
+    assert_eq!(board.get_cell(5).board(), &inner);
+    assert_eq!(
+        board.get_cell(7).board(),
+        &InnerBoard::builder().x(1).build()
+    );
+    assert_eq!(board.get_cell(3).summary(), RecursiveCell::new().summary());
+}
+
+#[test]
+fn recursive_board_debug_round_trip() {
+    let board = RecursiveBoard::from_owners([
+        Some(Player::Cross),
+        None,
+        None,
+        None,
+        Some(Player::Circle),
+        None,
+        None,
+        None,
+        Some(Player::Cross),
+    ]);
+    let diagram = format!("{board:#?}");
+
+    assert_eq!(
+        format!("{:?}", RecursiveBoard::from_diagram(&diagram).unwrap()),
+        format!("{board:?}")
+    );
+}
+
+#[test]
+fn recursive_board_playable_mask() {
+    let mut board = RecursiveBoard::new();
+    for inner in [0, 4, 8] {
+        board.try_set_cell(2, inner, Player::Circle).unwrap();
+    }
+    board.try_set_cell(7, 3, Player::Cross).unwrap();
+
+    let mask = board.playable_mask();
+    assert_eq!(
+        mask.count_ones(),
+        81 - 9 - 1,
+        "Doesn't skip decided boards."
+    );
+    for outer in 0..9 {
+        for inner in 0..9 {
+            let position = CellPosition::new(outer, inner);
+            assert_eq!(
+                mask & position.bit() != 0,
+                board
+                    .clone()
+                    .try_set_cell(outer, inner, Player::Cross)
+                    .is_ok(),
+                "Disagrees with try_set_cell on {position}"
+            );
+        }
+    }
+}
+
+#[test]
+fn recursive_board_compressed_key_is_unique_in_progress() {
+    let mut keys = std::collections::HashSet::new();
+    assert!(keys.insert(RecursiveBoard::new().compressed_key()));
+    for player in [Player::Cross, Player::Circle] {
+        for outer in 0..9 {
+            for inner in 0..9 {
+                let mut board = RecursiveBoard::new();
+                board.try_set_cell(outer, inner, player).unwrap();
+                assert!(
+                    keys.insert(board.compressed_key()),
+                    "Two different boards share a key."
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "checked-index")]
+#[test]
+#[should_panic(expected = "RecursiveBoard index out of bounds: the index is 9")]
+fn checked_index_names_the_board() {
+    RecursiveBoard::new().get_cell(9);
+}
+
+#[test]
+fn recursive_board_draw_with_drawn_sub_boards() {
+    use std::str::FromStr;
+
+    let drawn = InnerBoard::from_str("OXOXXOXOX").unwrap();
+    let (x, o) = (Player::Cross, Player::Circle);
+    let mut board = RecursiveBoard::from_owners([
+        Some(x),
+        Some(o),
+        Some(x),
+        None,
+        None,
+        None,
+        Some(o),
+        Some(x),
+        Some(o),
+    ]);
+    for outer in 3..6 {
+        board.fill_sub_board(outer, drawn.clone());
+    }
+
+    assert_eq!(
+        board.get_state(),
+        BoardState::Over(BoardResult::Draw),
+        "Doesn't count drawn sub-boards as finished."
+    );
+    assert_eq!(
+        board.get_state_with_tie_break(recursive::TieBreak::MostBoards),
+        BoardState::Over(BoardResult::Draw),
+        "Doesn't keep the draw when both players won as many boards."
+    );
+}
+
+#[test]
+fn recursive_board_describe() {
+    use std::str::FromStr;
+
+    assert_eq!(
+        RecursiveBoard::new().describe(),
+        "No board has been decided yet."
+    );
+
+    let (x, o) = (Some(Player::Cross), Some(Player::Circle));
+    let mut board = RecursiveBoard::from_owners([x, None, o, None, x, None, o, None, None]);
+    board.fill_sub_board(1, InnerBoard::from_str("OXOXXOXOX").unwrap());
+    assert_eq!(
+        board.describe(),
+        "X has won the top-left and center boards. O has won the top-right and bottom-left boards. \
+         The top board is drawn. X threatens the main diagonal of the main board."
+    );
+
+    for inner in [0, 1] {
+        board.try_set_cell(8, inner, Player::Cross).unwrap();
+    }
+    let mv = CellPosition::new(8, 2).by(Player::Cross);
+    board.try_play(mv).unwrap();
+    assert_eq!(
+        board.describe_move(mv),
+        "X plays in the bottom-right board, top-right cell. X wins the bottom-right board. \
+         X wins the game."
+    );
+    assert_eq!(
+        board.describe(),
+        "X has won the top-left, center and bottom-right boards. \
+         O has won the top-right and bottom-left boards. The top board is drawn. X has won the game."
+    );
+}
+
+#[test]
+fn cell_groups_match_win_lines() {
+    let lines_through = |cell: usize| WIN_LINES.iter().filter(|line| line.contains(&cell)).count();
+
+    let mut cells: Vec<usize> = CORNERS.iter().chain(&EDGES).copied().collect();
+    cells.push(CENTER);
+    cells.sort_unstable();
+    assert_eq!(cells, (0..9).collect::<Vec<_>>());
+
+    assert_eq!(lines_through(CENTER), 4);
+    assert!(CORNERS.iter().all(|&cell| lines_through(cell) == 3));
+    assert!(EDGES.iter().all(|&cell| lines_through(cell) == 2));
+}
+
+#[test]
+fn from_cells_round_trips_map_cells() {
+    let board = RecursiveBoard::builder()
+        .owner(0, Player::Cross)
+        .mark(4, 4, Player::Circle)
+        .build();
+    let copy = RecursiveBoard::try_from_cells(board.map_cells(Clone::clone)).unwrap();
+    assert_eq!(copy.to_string(), board.to_string());
+    assert_eq!(copy.compressed_key(), board.compressed_key());
+
+    let cells = || std::iter::repeat(Some(Player::Cross));
+    assert_eq!(InnerBoard::try_from_cells(cells().take(8)), None);
+    assert_eq!(InnerBoard::try_from_cells(cells().take(10)), None);
+    assert_eq!(
+        InnerBoard::try_from_cells(cells().take(9)),
+        Some(
+            InnerBoard::builder()
+                .x(0)
+                .x(1)
+                .x(2)
+                .x(3)
+                .x(4)
+                .x(5)
+                .x(6)
+                .x(7)
+                .x(8)
+                .build()
+        )
+    );
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_boards_are_reachable() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    // A simple xorshift, so the data is varied but the test deterministic.
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut data = vec![0u8; 4096];
+    for _ in 0..200 {
+        for byte in &mut data {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *byte = state as u8;
+        }
+        let mut u = Unstructured::new(&data);
+        let board = RecursiveBoard::arbitrary(&mut u).unwrap();
+        assert!(board.is_reachable(), "{board:#?}");
+
+        let inner = InnerBoard::arbitrary(&mut u).unwrap();
+        let marks = |player| {
+            (0..9)
+                .filter(|&cell| inner.get_cell(cell) == &Some(player))
+                .count()
+        };
+        assert!(matches!(
+            marks(Player::Cross) - marks(Player::Circle),
+            0 | 1
+        ));
+
+        let position = CellPosition::arbitrary(&mut u).unwrap();
+        assert_eq!(CellPosition::from_index(position.index()), position);
+    }
+}
@@ -1,14 +1,286 @@
+use std::fmt::Display;
+
 #[derive(Debug, PartialEq, Eq)]
+/// Returned when trying to convert a [`char`] that doesn't represent any [`Player`](crate::Player).
 pub struct InvalidPlayerChar;
 
+impl Display for InvalidPlayerChar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "character does not represent a player")
+    }
+}
+
+impl std::error::Error for InvalidPlayerChar {}
+
 #[derive(Debug, PartialEq, Eq)]
+/// Returned when parsing an [`InnerBoard`](crate::board::InnerBoard) from a string fails.
+///
+/// Each variant carries enough context to point at the problem in the original input.
 pub enum InnerBoardFromStrError {
+    /// The string doesn't have exactly one character per cell.
+    InvalidLength {
+        /// The amount of characters a board needs.
+        expected: usize,
+        /// The amount of characters that were given.
+        actual: usize,
+    },
+    /// A character is neither a [`Player`](crate::Player) nor the empty cell marker (`-`).
+    InvalidChar {
+        /// The byte index of the character in the parsed string.
+        index: usize,
+        /// The offending character.
+        found: char,
+    },
+}
+
+impl Display for InnerBoardFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLength { expected, actual } => {
+                write!(f, "expected {expected} cells, found {actual}")
+            }
+            Self::InvalidChar { index, found } => {
+                write!(f, "invalid cell {found:?} at index {index}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InnerBoardFromStrError {}
+
+#[derive(Debug, PartialEq, Eq)]
+/// Returned when reading a [`CellPosition`](crate::board::CellPosition) from its algebraic name fails.
+pub enum CellPositionFromStrError {
+    /// The algebraic name isn't exactly two characters long.
     InvalidLength,
-    InvalidChars,
+    /// The first character isn't a column letter from `a` to `i`.
+    InvalidColumn {
+        /// The offending character.
+        found: char,
+    },
+    /// The second character isn't a row digit from `1` to `9`.
+    InvalidRow {
+        /// The offending character.
+        found: char,
+    },
+    /// The keypad name isn't exactly two characters long.
+    InvalidKeypadLength,
+    /// A character of a keypad name isn't a digit from `1` to `9`.
+    InvalidKey {
+        /// The offending character.
+        found: char,
+    },
+}
+
+impl Display for CellPositionFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "expected a column letter followed by a row digit"),
+            Self::InvalidColumn { found } => write!(f, "invalid column {found:?}"),
+            Self::InvalidRow { found } => write!(f, "invalid row {found:?}"),
+            Self::InvalidKeypadLength => {
+                write!(f, "expected a sub-board key followed by a cell key")
+            }
+            Self::InvalidKey { found } => write!(f, "invalid key {found:?}"),
+        }
+    }
+}
+
+impl std::error::Error for CellPositionFromStrError {}
+
+#[derive(Debug, PartialEq, Eq)]
+/// Returned when converting a [`usize`] that isn't from `0` to `8` into an
+/// [`OuterIdx`](crate::board::position::OuterIdx) or [`InnerIdx`](crate::board::position::InnerIdx).
+pub struct IndexOutOfRange {
+    /// The offending index.
+    pub index: usize,
+}
+
+impl Display for IndexOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "index {} is out of range, it must be less than 9",
+            self.index
+        )
+    }
+}
+
+impl std::error::Error for IndexOutOfRange {}
+
+#[derive(Debug, PartialEq, Eq)]
+/// Returned by [`RecursiveBoard::try_set_cell`](crate::board::RecursiveBoard::try_set_cell) when
+/// the mark can't be placed by the rules.
+pub enum SetCellError {
+    /// The sub-board has already been won or drawn, so no more marks can be placed on it.
+    BoardDecided {
+        /// The index of the sub-board in the outer board.
+        outer: usize,
+    },
+    /// The cell already has a mark in it.
+    CellOccupied {
+        /// The index of the sub-board in the outer board.
+        outer: usize,
+        /// The index of the cell in the sub-board.
+        inner: usize,
+    },
+}
+
+impl Display for SetCellError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BoardDecided { outer } => write!(f, "board {outer} has already been decided"),
+            Self::CellOccupied { outer, inner } => {
+                write!(f, "cell {inner} of board {outer} is already occupied")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SetCellError {}
+
+#[derive(Debug, PartialEq, Eq)]
+/// Returned when reading a [`RecursiveBoard`](crate::board::RecursiveBoard) from a text diagram
+/// fails. See [`RecursiveBoard::from_diagram`](crate::board::RecursiveBoard::from_diagram).
+///
+/// Lines and columns are counted from zero, in characters.
+pub enum DiagramError {
+    /// A character is neither a cell nor a separator.
+    InvalidChar {
+        /// The line the character is in.
+        line: usize,
+        /// The column of the character in its line.
+        column: usize,
+        /// The offending character.
+        found: char,
+    },
+    /// A row of the diagram doesn't have exactly nine cells.
+    InvalidRowLength {
+        /// The line the row is in.
+        line: usize,
+        /// The amount of cells found in the row.
+        cells: usize,
+    },
+    /// The diagram doesn't have exactly nine rows of cells.
+    InvalidRowCount {
+        /// The amount of rows found.
+        rows: usize,
+    },
+}
+
+impl Display for DiagramError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidChar {
+                line,
+                column,
+                found,
+            } => write!(f, "invalid character {found:?} at {line}:{column}"),
+            Self::InvalidRowLength { line, cells } => {
+                write!(
+                    f,
+                    "expected 9 cells in the row at line {line}, found {cells}"
+                )
+            }
+            Self::InvalidRowCount { rows } => write!(f, "expected 9 rows, found {rows}"),
+        }
+    }
+}
+
+impl std::error::Error for DiagramError {}
+
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+/// The crate-wide error type. Every error returned by this crate can be converted into it, so
+/// applications can use a single type with the `?` operator.
+///
+/// # Examples
+/// ```
+/// # use std::str::FromStr;
+/// use tic_tac_toe::{Error, Player, board::InnerBoard};
+///
+/// fn parse(board: &str, player: char) -> Result<(InnerBoard, Player), Error> {
+///     Ok((InnerBoard::from_str(board)?, Player::try_from(player)?))
+/// }
+///
+/// assert!(parse("OX-XXXO--", 'X').is_ok());
+/// assert!(matches!(parse("OX-", 'X'), Err(Error::InnerBoardFromStr(_))));
+/// assert!(matches!(parse("OX-XXXO--", 'a'), Err(Error::InvalidPlayerChar(_))));
+/// ```
+pub enum Error {
+    /// See [`InvalidPlayerChar`].
+    InvalidPlayerChar(InvalidPlayerChar),
+    /// See [`InnerBoardFromStrError`].
+    InnerBoardFromStr(InnerBoardFromStrError),
+    /// See [`CellPositionFromStrError`].
+    CellPositionFromStr(CellPositionFromStrError),
+    /// See [`IndexOutOfRange`].
+    IndexOutOfRange(IndexOutOfRange),
+    /// See [`SetCellError`].
+    SetCell(SetCellError),
+    /// See [`DiagramError`].
+    Diagram(DiagramError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidPlayerChar(error) => error.fmt(f),
+            Self::InnerBoardFromStr(error) => error.fmt(f),
+            Self::CellPositionFromStr(error) => error.fmt(f),
+            Self::IndexOutOfRange(error) => error.fmt(f),
+            Self::SetCell(error) => error.fmt(f),
+            Self::Diagram(error) => error.fmt(f),
+        }
+    }
+}
+
+/// The variants are transparent: both the message and the source are the wrapped error's.
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidPlayerChar(error) => error.source(),
+            Self::InnerBoardFromStr(error) => error.source(),
+            Self::CellPositionFromStr(error) => error.source(),
+            Self::IndexOutOfRange(error) => error.source(),
+            Self::SetCell(error) => error.source(),
+            Self::Diagram(error) => error.source(),
+        }
+    }
+}
+
+impl From<InvalidPlayerChar> for Error {
+    fn from(value: InvalidPlayerChar) -> Self {
+        Self::InvalidPlayerChar(value)
+    }
+}
+
+impl From<InnerBoardFromStrError> for Error {
+    fn from(value: InnerBoardFromStrError) -> Self {
+        Self::InnerBoardFromStr(value)
+    }
+}
+
+impl From<CellPositionFromStrError> for Error {
+    fn from(value: CellPositionFromStrError) -> Self {
+        Self::CellPositionFromStr(value)
+    }
+}
+
+impl From<IndexOutOfRange> for Error {
+    fn from(value: IndexOutOfRange) -> Self {
+        Self::IndexOutOfRange(value)
+    }
+}
+
+impl From<SetCellError> for Error {
+    fn from(value: SetCellError) -> Self {
+        Self::SetCell(value)
+    }
 }
 
-impl From<InvalidPlayerChar> for InnerBoardFromStrError {
-    fn from(_: InvalidPlayerChar) -> Self {
-        Self::InvalidChars
+impl From<DiagramError> for Error {
+    fn from(value: DiagramError) -> Self {
+        Self::Diagram(value)
     }
 }
@@ -0,0 +1,147 @@
+use crate::board::CellPosition;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// An axis-aligned rectangle in screen coordinates, with `y` growing downwards.
+pub struct Rect {
+    /// The horizontal position of the left edge.
+    pub x: f32,
+    /// The vertical position of the top edge.
+    pub y: f32,
+    /// How wide the rectangle is.
+    pub width: f32,
+    /// How tall the rectangle is.
+    pub height: f32,
+}
+
+impl Rect {
+    #[must_use]
+    /// Returns the rectangle with its top-left corner at (`x`, `y`) and the given size.
+    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[must_use]
+    /// Whether the point (`x`, `y`) is inside the rectangle. The top and left edges are inside,
+    /// the bottom and right ones are not, so adjacent rectangles never both contain a point.
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+
+    /// Splits the rectangle in a 3x3 grid with `gap` between the parts, and returns the `index`th
+    /// one, left to right and then top to bottom.
+    fn part(&self, index: usize, gap: f32) -> Self {
+        let width = (self.width - 2.0 * gap) / 3.0;
+        let height = (self.height - 2.0 * gap) / 3.0;
+        Self {
+            x: self.x + (index % 3) as f32 * (width + gap),
+            y: self.y + (index / 3) as f32 * (height + gap),
+            width,
+            height,
+        }
+    }
+
+    /// The inverse of [`Rect::part`]: which part of the grid (`x`, `y`) falls in, if any.
+    fn part_at(&self, x: f32, y: f32, gap: f32) -> Option<usize> {
+        (0..9).find(|&index| self.part(index, gap).contains(x, y))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Where the full game board is drawn on screen: the rectangles of each sub-board and cell, and
+/// which cell is under a given point.
+///
+/// The board is split in a 3x3 grid of sub-boards, separated by `board_gap`, and each sub-board
+/// in a 3x3 grid of cells, separated by `cell_gap`. Points that fall in a gap don't hit any cell.
+///
+/// # Examples
+/// ```
+/// use tic_tac_toe::{board::CellPosition, gui::{BoardLayout, Rect}};
+///
+/// // A 300x300 board with no gaps: each sub-board is 100 pixels wide, each cell about 33.
+/// let layout = BoardLayout::new(Rect::new(0.0, 0.0, 300.0, 300.0));
+///
+/// assert_eq!(layout.sub_board_rect(4), Rect::new(100.0, 100.0, 100.0, 100.0));
+/// assert_eq!(layout.hit_test(150.0, 150.0), Some(CellPosition::new(4, 4)));
+/// assert_eq!(layout.hit_test(10.0, 290.0), Some(CellPosition::new(6, 6)));
+/// assert_eq!(layout.hit_test(310.0, 10.0), None);
+/// ```
+pub struct BoardLayout {
+    /// The area the whole board is drawn in.
+    pub bounds: Rect,
+    /// The space between sub-boards.
+    pub board_gap: f32,
+    /// The space between the cells of a sub-board.
+    pub cell_gap: f32,
+}
+
+impl BoardLayout {
+    #[must_use]
+    /// Returns a layout filling `bounds`, without gaps between sub-boards or cells.
+    pub const fn new(bounds: Rect) -> Self {
+        Self {
+            bounds,
+            board_gap: 0.0,
+            cell_gap: 0.0,
+        }
+    }
+
+    #[must_use]
+    /// Returns the layout with the given gaps between sub-boards and between cells.
+    pub const fn with_gaps(self, board_gap: f32, cell_gap: f32) -> Self {
+        Self {
+            board_gap,
+            cell_gap,
+            ..self
+        }
+    }
+
+    #[must_use]
+    /// Returns the area the `outer` sub-board is drawn in.
+    pub fn sub_board_rect(&self, outer: usize) -> Rect {
+        debug_assert!(outer < 9);
+        self.bounds.part(outer, self.board_gap)
+    }
+
+    #[must_use]
+    /// Returns the area the cell at `position` is drawn in.
+    pub fn cell_rect(&self, position: CellPosition) -> Rect {
+        self.sub_board_rect(position.outer().get())
+            .part(position.inner().get(), self.cell_gap)
+    }
+
+    #[must_use]
+    /// Returns the cell drawn under the point (`x`, `y`), if there is one.
+    pub fn hit_test(&self, x: f32, y: f32) -> Option<CellPosition> {
+        let outer = self.bounds.part_at(x, y, self.board_gap)?;
+        let inner = self.sub_board_rect(outer).part_at(x, y, self.cell_gap)?;
+        Some(CellPosition::new(outer, inner))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hit_test_matches_cell_rects() {
+        let layout = BoardLayout::new(Rect::new(20.0, 40.0, 450.0, 360.0)).with_gaps(12.0, 3.0);
+
+        for outer in 0..9 {
+            for inner in 0..9 {
+                let position = CellPosition::new(outer, inner);
+                let rect = layout.cell_rect(position);
+                let (x, y) = (rect.x + rect.width / 2.0, rect.y + rect.height / 2.0);
+                assert_eq!(layout.hit_test(x, y), Some(position));
+            }
+        }
+
+        // Right between the first two sub-boards.
+        let first = layout.sub_board_rect(0);
+        assert_eq!(layout.hit_test(first.x + first.width + 6.0, first.y), None);
+    }
+}
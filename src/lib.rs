@@ -2,6 +2,24 @@
 //! This crate is an implementation of a recursive Tic-Tac-Toe game, also known as the
 //! "**Ultimate Tic-Tac-Toe**".
 
+/// Checks that `$index` is a valid cell index for a 3x3 board. Only in debug builds by default; with
+/// the `checked-index` feature, always, and the panic names the `$board` that was indexed.
+macro_rules! check_index {
+    ($board:literal, $index:expr) => {
+        #[cfg(feature = "checked-index")]
+        assert!(
+            $index < 9,
+            concat!(
+                $board,
+                " index out of bounds: the index is {} but it must be less than 9"
+            ),
+            $index
+        );
+        #[cfg(not(feature = "checked-index"))]
+        debug_assert!($index < 9);
+    };
+}
+
 /// Handles everything that has direct relation to the management of the game board.
 /// Is driven by the [`Board`](board::Board) trait.
 ///
@@ -9,7 +27,13 @@
 /// for this module.
 pub mod board;
 
-pub(crate) mod errors;
+/// Geometry helpers for graphical front-ends: where each sub-board and cell is drawn, and which
+/// [`CellPosition`](board::CellPosition) is under the pointer.
+pub mod gui;
+
+/// Contains the error types returned throughout the crate, such as when parsing boards from strings.
+pub mod errors;
+pub use errors::Error;
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 /// Represents the result of a finished board: either a player has won or it's a draw.
@@ -34,7 +58,7 @@ pub enum BoardState {
     Over(BoardResult),
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
 /// Represents a player.
 ///
 /// Currently only circle and cross but maybe could have multiplayer later on.
@@ -45,6 +69,122 @@ pub enum Player {
     Cross,
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Player {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(if u.arbitrary()? {
+            Self::Circle
+        } else {
+            Self::Cross
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <bool as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+impl Player {
+    #[must_use]
+    /// Returns the other [`Player`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use tic_tac_toe::Player;
+    /// assert_eq!(Player::Circle.opponent(), Player::Cross);
+    /// assert_eq!(Player::Cross.opponent(), Player::Circle);
+    /// ```
+    pub const fn opponent(&self) -> Self {
+        match self {
+            Self::Circle => Self::Cross,
+            Self::Cross => Self::Circle,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+/// How each [`Player`] is presented to people: a name for text, and a mark for board diagrams.
+///
+/// By default both are the usual `X` and `O`. Applications can swap them for numbered players,
+/// actual names or localized strings.
+///
+/// # Examples
+/// ```
+/// use tic_tac_toe::{Player, PlayerLabels};
+///
+/// let labels = PlayerLabels::numbered();
+/// assert_eq!(labels.name(Player::Cross), "Player 1");
+/// assert_eq!(labels.mark(Player::Cross), 'X');
+///
+/// let labels = PlayerLabels::new("Ana", "Bruno").with_marks('A', 'B');
+/// assert_eq!(labels.name(Player::Circle), "Bruno");
+/// assert_eq!(labels.mark(Player::Circle), 'B');
+/// ```
+pub struct PlayerLabels {
+    cross_name: String,
+    circle_name: String,
+    cross_mark: char,
+    circle_mark: char,
+}
+
+impl PlayerLabels {
+    #[must_use]
+    /// Returns labels with the given names, keeping the default `X` and `O` marks.
+    pub fn new(cross_name: impl Into<String>, circle_name: impl Into<String>) -> Self {
+        Self {
+            cross_name: cross_name.into(),
+            circle_name: circle_name.into(),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    /// Returns labels naming [`Player::Cross`] "Player 1" and [`Player::Circle`] "Player 2".
+    pub fn numbered() -> Self {
+        Self::new("Player 1", "Player 2")
+    }
+
+    #[must_use]
+    /// Returns the labels with the given marks for [`Player::Cross`] and [`Player::Circle`].
+    pub fn with_marks(self, cross_mark: char, circle_mark: char) -> Self {
+        Self {
+            cross_mark,
+            circle_mark,
+            ..self
+        }
+    }
+
+    #[must_use]
+    /// Returns the name of `player`.
+    pub fn name(&self, player: Player) -> &str {
+        match player {
+            Player::Cross => &self.cross_name,
+            Player::Circle => &self.circle_name,
+        }
+    }
+
+    #[must_use]
+    /// Returns the mark of `player`, as shown in board diagrams.
+    pub fn mark(&self, player: Player) -> char {
+        match player {
+            Player::Cross => self.cross_mark,
+            Player::Circle => self.circle_mark,
+        }
+    }
+}
+
+/// The usual `X` and `O`, for both names and marks.
+impl Default for PlayerLabels {
+    fn default() -> Self {
+        Self {
+            cross_name: String::from("X"),
+            circle_name: String::from("O"),
+            cross_mark: 'X',
+            circle_mark: 'O',
+        }
+    }
+}
+
 /// The [`Player`] should be representable by a single [`char`]`.
 ///
 /// # Examples
@@ -62,7 +202,6 @@ impl From<&Player> for char {
     }
 }
 
-
 /// The [`Player`] has representable forms as [`char`]s.
 ///
 /// # Examples
@@ -76,7 +215,6 @@ impl From<&Player> for char {
 /// assert!(Player::try_from('o').is_err());
 /// assert!(Player::try_from('A').is_err());
 /// ```
-
 impl TryFrom<char> for Player {
     type Error = errors::InvalidPlayerChar;
     fn try_from(value: char) -> Result<Self, Self::Error> {